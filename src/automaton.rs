@@ -0,0 +1,415 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    mem,
+};
+
+use crate::{Connectivity, Dir4, Dir8, Grid, Point};
+
+/// A cellular automaton that can be stepped one generation at a time.
+///
+/// Implemented by [`GridAutomaton`] for dense grids and [`SparseAutomaton`] for unbounded spaces of any dimension.
+/// The provided methods drive any implementation: running for a number of generations, running until stable,
+/// and detecting cycles.
+pub trait Automaton {
+    /// Advances the automaton by one generation, returning whether any cell changed.
+    fn step(&mut self) -> bool;
+
+    /// Returns the number of generations stepped so far.
+    fn generation(&self) -> usize;
+
+    /// Advances the automaton by `n` generations.
+    fn run(&mut self, n: usize) {
+        for _ in 0..n {
+            self.step();
+        }
+    }
+
+    /// Advances the automaton until a step changes nothing, returning the number of steps that changed something.
+    ///
+    /// Never returns if the automaton never stabilises.
+    fn run_until_stable(&mut self) -> usize {
+        let start = self.generation();
+        while self.step() {}
+        self.generation() - start - 1
+    }
+
+    /// Advances the automaton until a state repeats, and returns the cycle found.
+    ///
+    /// `key` is called once per generation, starting with the current one, and should return a value that identifies
+    /// the state, such as a copy of the cells or a hash of them. Two generations are considered the same state
+    /// if their keys are equal. The keys are kept in the returned `Cycle`,
+    /// so the key can also carry whatever is needed from each generation, such as a score.
+    ///
+    /// Never returns if the automaton never repeats a state.
+    fn find_cycle<K: Hash + Eq + Clone>(&mut self, mut key: impl FnMut(&Self) -> K) -> Cycle<K>
+    where
+        Self: Sized,
+    {
+        let offset = self.generation();
+        let mut seen = HashMap::new();
+        let mut keys = Vec::new();
+        loop {
+            let k = key(self);
+            if let Some(&start) = seen.get(&k) {
+                return Cycle {
+                    offset,
+                    start,
+                    period: keys.len() - start,
+                    keys,
+                };
+            }
+            seen.insert(k.clone(), keys.len());
+            keys.push(k);
+            self.step();
+        }
+    }
+}
+
+/// A cycle in the states of an [`Automaton`], as returned by [`Automaton::find_cycle`].
+#[derive(Clone, Debug)]
+pub struct Cycle<K> {
+    offset: usize,
+    start: usize,
+    period: usize,
+    keys: Vec<K>,
+}
+
+impl<K> Cycle<K> {
+    /// Returns the first generation that is part of the cycle.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.offset + self.start
+    }
+
+    /// Returns the number of generations in the cycle.
+    #[inline]
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Returns the earliest generation with the same state as `generation`.
+    ///
+    /// Panics if `generation` is before the generation at which cycle detection started.
+    #[track_caller]
+    pub fn equivalent(&self, generation: usize) -> usize {
+        let index = generation
+            .checked_sub(self.offset)
+            .expect("`generation` should not be before cycle detection started");
+        if index < self.start {
+            generation
+        } else {
+            self.start() + (index - self.start) % self.period
+        }
+    }
+
+    /// Returns the key of the state at `generation`, which may be far beyond the generations actually stepped.
+    ///
+    /// Panics if `generation` is before the generation at which cycle detection started.
+    #[track_caller]
+    pub fn key_at(&self, generation: usize) -> &K {
+        &self.keys[self.equivalent(generation) - self.offset]
+    }
+}
+
+/// The neighbours of a cell in a [`GridAutomaton`], passed to its rule.
+///
+/// Iterates over the values of the neighbouring cells inside the grid.
+/// The whole grid and the position of the cell are also available, for rules that look further afield.
+#[derive(Clone, Debug)]
+pub struct Neighbours<'a, T> {
+    grid: &'a Grid<T>,
+    point: Point,
+    offsets: std::slice::Iter<'a, Point>,
+}
+
+impl<'a, T> Neighbours<'a, T> {
+    /// Returns the grid of the current generation.
+    #[inline(always)]
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// Returns the position of the cell being updated.
+    #[inline(always)]
+    pub fn point(&self) -> Point {
+        self.point
+    }
+}
+
+impl<'a, T> Iterator for Neighbours<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for &offset in self.offsets.by_ref() {
+            if let Some(cell) = self.grid.get(self.point + offset) {
+                return Some(cell);
+            }
+        }
+        None
+    }
+}
+
+/// A cellular automaton on a dense [`Grid`], where cells outside the grid are treated as absent.
+///
+/// Each generation, the rule is called for every cell with its current value and its [`Neighbours`],
+/// and returns the cell's value in the next generation. The next generation is written to a second buffer,
+/// which is swapped in after the step, so no allocations are made after construction.
+///
+/// # Examples
+/// ```
+/// use aoc::{Automaton, Connectivity, Grid, GridAutomaton};
+///
+/// let grid = Grid::from_input([".#.", ".#.", ".#."]);
+/// let mut life = GridAutomaton::new(grid, Connectivity::Eight, |&cell, neighbours| {
+///     match (cell, neighbours.filter(|&&n| n == b'#').count()) {
+///         (b'#', 2 | 3) | (b'.', 3) => b'#',
+///         _ => b'.',
+///     }
+/// });
+///
+/// life.step();
+/// assert_eq!(life.grid().to_string(), "...\n###\n...");
+///
+/// let cycle = life.find_cycle(|life| life.grid().clone());
+/// assert_eq!((cycle.start(), cycle.period()), (1, 2));
+/// assert_eq!(cycle.key_at(1_000_000).to_string(), ".#.\n.#.\n.#.");
+/// ```
+#[derive(Clone, Debug)]
+pub struct GridAutomaton<T, R> {
+    current: Grid<T>,
+    next: Grid<T>,
+    offsets: Vec<Point>,
+    rule: R,
+    generation: usize,
+}
+
+impl<T, R> GridAutomaton<T, R>
+where
+    T: Clone + PartialEq,
+    R: FnMut(&T, Neighbours<'_, T>) -> T,
+{
+    /// Creates an automaton starting from `grid`, with the neighbourhood given by `connectivity`.
+    pub fn new(grid: Grid<T>, connectivity: Connectivity, rule: R) -> Self {
+        let offsets = match connectivity {
+            Connectivity::Four => Dir4::ALL.map(Dir4::offset).to_vec(),
+            Connectivity::Eight => Dir8::ALL.map(Dir8::offset).to_vec(),
+        };
+        Self::with_offsets(grid, offsets, rule)
+    }
+
+    /// Creates an automaton starting from `grid`, where the neighbours of a cell are at the given offsets from it.
+    pub fn with_offsets(grid: Grid<T>, offsets: Vec<Point>, rule: R) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            offsets,
+            rule,
+            generation: 0,
+        }
+    }
+
+    /// Returns the grid of the current generation.
+    #[inline(always)]
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    /// Returns the grid of the current generation, consuming `self`.
+    #[inline]
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+}
+
+impl<T, R> Automaton for GridAutomaton<T, R>
+where
+    T: Clone + PartialEq,
+    R: FnMut(&T, Neighbours<'_, T>) -> T,
+{
+    fn step(&mut self) -> bool {
+        let mut changed = false;
+        for (point, cell) in self.current.iter() {
+            let neighbours = Neighbours {
+                grid: &self.current,
+                point,
+                offsets: self.offsets.iter(),
+            };
+            let value = (self.rule)(cell, neighbours);
+            changed |= value != *cell;
+            self.next[point] = value;
+        }
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    #[inline(always)]
+    fn generation(&self) -> usize {
+        self.generation
+    }
+}
+
+/// A coordinate type for a [`SparseAutomaton`].
+///
+/// Implemented for [`Point`] and for arrays `[i64; N]` of any dimension.
+pub trait Coord: Copy + Eq + Hash {
+    /// Returns `self` moved by `offset`.
+    fn offset(self, offset: Self) -> Self;
+
+    /// Returns the offsets to every orthogonally or diagonally adjacent coordinate.
+    fn moore() -> Vec<Self>;
+
+    /// Returns the offsets to every orthogonally adjacent coordinate.
+    fn von_neumann() -> Vec<Self>;
+}
+
+impl Coord for Point {
+    #[inline]
+    fn offset(self, offset: Self) -> Self {
+        self + offset
+    }
+
+    fn moore() -> Vec<Self> {
+        Dir8::ALL.map(Dir8::offset).to_vec()
+    }
+
+    fn von_neumann() -> Vec<Self> {
+        Dir4::ALL.map(Dir4::offset).to_vec()
+    }
+}
+
+impl<const N: usize> Coord for [i64; N] {
+    #[inline]
+    fn offset(mut self, offset: Self) -> Self {
+        for (a, b) in self.iter_mut().zip(offset) {
+            *a += b;
+        }
+        self
+    }
+
+    fn moore() -> Vec<Self> {
+        let mut offsets = vec![[0; N]];
+        for axis in 0..N {
+            offsets = offsets
+                .into_iter()
+                .flat_map(|o: [i64; N]| {
+                    [-1, 0, 1].map(|d| {
+                        let mut o = o;
+                        o[axis] = d;
+                        o
+                    })
+                })
+                .collect();
+        }
+        offsets.retain(|o| *o != [0; N]);
+        offsets
+    }
+
+    fn von_neumann() -> Vec<Self> {
+        (0..N)
+            .flat_map(|axis| {
+                [-1, 1].map(|d| {
+                    let mut o = [0; N];
+                    o[axis] = d;
+                    o
+                })
+            })
+            .collect()
+    }
+}
+
+/// A cellular automaton of live and dead cells in an unbounded space of any dimension,
+/// storing only the live cells.
+///
+/// Each generation, the rule is called with whether a cell is alive and how many of its neighbours are alive,
+/// for every cell that is alive or has a live neighbour, and returns whether the cell is alive in the next generation.
+/// The buffers for the next generation are kept between steps.
+///
+/// # Examples
+/// ```
+/// use aoc::{Automaton, Coord, SparseAutomaton};
+///
+/// // Conway Cubes: a glider in 3 dimensions.
+/// let live = [[1, 0, 0], [2, 1, 0], [0, 2, 0], [1, 2, 0], [2, 2, 0]];
+/// let mut cubes = SparseAutomaton::new(live, <[i64; 3]>::moore(), |alive, n| {
+///     matches!((alive, n), (true, 2 | 3) | (false, 3))
+/// });
+///
+/// cubes.run(6);
+/// assert_eq!(cubes.live().len(), 112);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SparseAutomaton<P, R> {
+    live: HashSet<P>,
+    next: HashSet<P>,
+    counts: HashMap<P, usize>,
+    offsets: Vec<P>,
+    rule: R,
+    generation: usize,
+}
+
+impl<P, R> SparseAutomaton<P, R>
+where
+    P: Coord,
+    R: FnMut(bool, usize) -> bool,
+{
+    /// Creates an automaton with the given live cells, where the neighbours of a cell are at the given offsets from it.
+    pub fn new(live: impl IntoIterator<Item = P>, offsets: Vec<P>, rule: R) -> Self {
+        Self {
+            live: live.into_iter().collect(),
+            next: HashSet::new(),
+            counts: HashMap::new(),
+            offsets,
+            rule,
+            generation: 0,
+        }
+    }
+
+    /// Returns the live cells of the current generation.
+    #[inline(always)]
+    pub fn live(&self) -> &HashSet<P> {
+        &self.live
+    }
+
+    /// Returns the live cells of the current generation, consuming `self`.
+    #[inline]
+    pub fn into_live(self) -> HashSet<P> {
+        self.live
+    }
+}
+
+impl<P, R> Automaton for SparseAutomaton<P, R>
+where
+    P: Coord,
+    R: FnMut(bool, usize) -> bool,
+{
+    fn step(&mut self) -> bool {
+        self.counts.clear();
+        for &p in &self.live {
+            self.counts.entry(p).or_insert(0);
+            for &offset in &self.offsets {
+                *self.counts.entry(p.offset(offset)).or_insert(0) += 1;
+            }
+        }
+        self.next.clear();
+        let mut changed = false;
+        for (&p, &n) in &self.counts {
+            let alive = self.live.contains(&p);
+            let next = (self.rule)(alive, n);
+            changed |= next != alive;
+            if next {
+                self.next.insert(p);
+            }
+        }
+        mem::swap(&mut self.live, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    #[inline(always)]
+    fn generation(&self) -> usize {
+        self.generation
+    }
+}
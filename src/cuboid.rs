@@ -0,0 +1,272 @@
+use crate::{Integer, Vector};
+
+/// An axis-aligned box in `N` dimensions, including both corners.
+///
+/// # Examples
+/// ```
+/// use aoc::{Cuboid, Parse, Vec3};
+///
+/// let a = Cuboid::from("x=10..12,y=10..12,z=10..12".ints::<6, i64>());
+/// let b = Cuboid::from("x=11..13,y=11..13,z=11..13".ints::<6, i64>());
+///
+/// assert_eq!(a.volume(), 27);
+/// assert_eq!(a.min_corner(), Vec3::new([10; 3]));
+/// assert_eq!(b.max_corner(), Vec3::new([13; 3]));
+/// assert!(a.contains(Vec3::new([12, 10, 11])));
+/// assert_eq!(a.intersect(&b), Some(Cuboid::new(Vec3::new([11; 3]), Vec3::new([12; 3]))));
+/// assert_eq!(a.subtract(&b).iter().map(Cuboid::volume).sum::<i64>(), 27 - 8);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cuboid<const N: usize, T = i64> {
+    min: Vector<N, T>,
+    max: Vector<N, T>,
+}
+
+/// A 2-dimensional [`Cuboid`].
+pub type Rect<T = i64> = Cuboid<2, T>;
+
+impl<const N: usize, T: Integer> Cuboid<N, T> {
+    /// Creates a new `Cuboid` with the given minimum and maximum corners, inclusive.
+    ///
+    /// Panics if any component of `min` is greater than the same component of `max`.
+    #[inline]
+    #[track_caller]
+    pub fn new(min: Vector<N, T>, max: Vector<N, T>) -> Self {
+        if (0..N).any(|i| min[i] > max[i]) {
+            panic!("`min` should not be greater than `max` in any dimension");
+        }
+        Self { min, max }
+    }
+
+    /// Creates the smallest `Cuboid` containing both `a` and `b`.
+    #[inline]
+    pub fn from_corners(a: Vector<N, T>, b: Vector<N, T>) -> Self {
+        Self {
+            min: a.component_min(b),
+            max: a.component_max(b),
+        }
+    }
+
+    /// Creates a new `Cuboid` from its minimum corner and its size in each dimension.
+    ///
+    /// Panics if any component of `size` is zero.
+    #[inline]
+    #[track_caller]
+    pub fn from_size(min: Vector<N, T>, size: Vector<N, T>) -> Self {
+        Self::new(min, min + size - Vector::splat(T::ONE))
+    }
+
+    /// Returns the minimum corner.
+    #[inline(always)]
+    pub fn min_corner(&self) -> Vector<N, T> {
+        self.min
+    }
+
+    /// Returns the maximum corner.
+    #[inline(always)]
+    pub fn max_corner(&self) -> Vector<N, T> {
+        self.max
+    }
+
+    /// Returns the number of integer points along each dimension.
+    #[inline]
+    pub fn size(&self) -> Vector<N, T> {
+        self.max - self.min + Vector::splat(T::ONE)
+    }
+
+    /// Returns the number of integer points in the box.
+    #[inline]
+    pub fn volume(&self) -> T {
+        self.size().0.into_iter().fold(T::ONE, |v, a| v * a)
+    }
+
+    /// Returns whether `point` is inside the box.
+    #[inline]
+    pub fn contains(&self, point: Vector<N, T>) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    /// Returns whether `other` is entirely inside `self`.
+    #[inline]
+    pub fn contains_cuboid(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// Returns whether `self` and `other` have any points in common.
+    #[inline]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    /// Returns the points that are in both `self` and `other`, or `None` if there are none.
+    #[inline]
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        self.overlaps(other).then(|| Self {
+            min: self.min.component_max(other.min),
+            max: self.max.component_min(other.max),
+        })
+    }
+
+    /// Returns disjoint boxes covering exactly the points that are in `self` but not in `other`.
+    ///
+    /// Returns at most `2 * N` boxes.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(overlap) = self.intersect(other) else {
+            return vec![*self];
+        };
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        for i in 0..N {
+            if rest.min[i] < overlap.min[i] {
+                let mut below = rest;
+                below.max[i] = overlap.min[i] - T::ONE;
+                pieces.push(below);
+                rest.min[i] = overlap.min[i];
+            }
+            if overlap.max[i] < rest.max[i] {
+                let mut above = rest;
+                above.min[i] = overlap.max[i] + T::ONE;
+                pieces.push(above);
+                rest.max[i] = overlap.max[i];
+            }
+        }
+        pieces
+    }
+}
+
+macro_rules! impl_from_ints {
+    ($n:literal, $len:literal) => {
+        impl<T: Integer> From<[T; $len]> for Cuboid<$n, T> {
+            /// Creates a box from the bounds of each dimension in turn, as parsed from `x=a..b,y=c..d` and similar.
+            ///
+            /// The bounds of each dimension may be in either order.
+            fn from(ints: [T; $len]) -> Self {
+                let mut min = Vector::ZERO;
+                let mut max = Vector::ZERO;
+                for i in 0..$n {
+                    min[i] = ints[2 * i].min(ints[2 * i + 1]);
+                    max[i] = ints[2 * i].max(ints[2 * i + 1]);
+                }
+                Self { min, max }
+            }
+        }
+    };
+}
+
+impl_from_ints!(1, 2);
+impl_from_ints!(2, 4);
+impl_from_ints!(3, 6);
+impl_from_ints!(4, 8);
+
+/// A set of points, stored as a union of disjoint [`Cuboid`]s.
+///
+/// Boxes can be added and removed in any order, overlapping or not, and the total volume is always available.
+///
+/// # Examples
+/// ```
+/// use aoc::{Cuboid, CuboidSet, Parse};
+///
+/// let steps = [
+///     "on x=10..12,y=10..12,z=10..12",
+///     "on x=11..13,y=11..13,z=11..13",
+///     "off x=9..11,y=9..11,z=9..11",
+///     "on x=10..10,y=10..10,z=10..10",
+/// ];
+/// let mut reactor = CuboidSet::new();
+/// for step in steps {
+///     reactor.set(Cuboid::from(step.ints::<6, i64>()), step.starts_with("on"));
+/// }
+///
+/// assert_eq!(reactor.volume(), 39);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CuboidSet<const N: usize, T = i64> {
+    cuboids: Vec<Cuboid<N, T>>,
+}
+
+impl<const N: usize, T: Integer> CuboidSet<N, T> {
+    /// Creates an empty `CuboidSet`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            cuboids: Vec::new(),
+        }
+    }
+
+    /// Adds every point in `cuboid` to the set.
+    pub fn insert(&mut self, cuboid: Cuboid<N, T>) {
+        self.remove(&cuboid);
+        self.cuboids.push(cuboid);
+    }
+
+    /// Removes every point in `cuboid` from the set.
+    pub fn remove(&mut self, cuboid: &Cuboid<N, T>) {
+        let mut kept = Vec::with_capacity(self.cuboids.len());
+        for existing in self.cuboids.drain(..) {
+            if existing.overlaps(cuboid) {
+                kept.extend(existing.subtract(cuboid));
+            } else {
+                kept.push(existing);
+            }
+        }
+        self.cuboids = kept;
+    }
+
+    /// Adds every point in `cuboid` to the set if `on` is `true`, or removes them otherwise.
+    #[inline]
+    pub fn set(&mut self, cuboid: Cuboid<N, T>, on: bool) {
+        if on {
+            self.insert(cuboid);
+        } else {
+            self.remove(&cuboid);
+        }
+    }
+
+    /// Returns whether `point` is in the set.
+    #[inline]
+    pub fn contains(&self, point: Vector<N, T>) -> bool {
+        self.cuboids.iter().any(|c| c.contains(point))
+    }
+
+    /// Returns the number of points in the set.
+    #[inline]
+    pub fn volume(&self) -> T {
+        self.cuboids
+            .iter()
+            .fold(T::ZERO, |total, c| total + c.volume())
+    }
+
+    /// Returns the set restricted to the points inside `bounds`.
+    pub fn clipped(&self, bounds: &Cuboid<N, T>) -> Self {
+        Self {
+            cuboids: self
+                .cuboids
+                .iter()
+                .filter_map(|c| c.intersect(bounds))
+                .collect(),
+        }
+    }
+
+    /// Returns the disjoint boxes making up the set, in no particular order.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[Cuboid<N, T>] {
+        &self.cuboids
+    }
+}
+
+impl<const N: usize, T: Integer> FromIterator<Cuboid<N, T>> for CuboidSet<N, T> {
+    /// Creates the union of possibly overlapping boxes.
+    fn from_iter<I: IntoIterator<Item = Cuboid<N, T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const N: usize, T: Integer> Extend<Cuboid<N, T>> for CuboidSet<N, T> {
+    fn extend<I: IntoIterator<Item = Cuboid<N, T>>>(&mut self, iter: I) {
+        for cuboid in iter {
+            self.insert(cuboid);
+        }
+    }
+}
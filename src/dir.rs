@@ -0,0 +1,226 @@
+use crate::Point;
+
+/// One of the 4 orthogonal directions on a 2D grid, with `y` increasing downwards.
+///
+/// # Examples
+/// ```
+/// use aoc::{Dir4, Point};
+///
+/// let dir = Dir4::from_byte(b'^');
+///
+/// assert_eq!(dir, Dir4::Up);
+/// assert_eq!(dir.turn_right(), Dir4::Right);
+/// assert_eq!(dir.turn_left(), Dir4::Left);
+/// assert_eq!(dir.reverse(), Dir4::Down);
+/// assert_eq!(dir.offset(), Point::new(0, -1));
+/// assert_eq!(Dir4::from_byte(b'W'), Dir4::Left);
+/// assert_eq!(Dir4::try_from_byte(b'?'), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All 4 directions, clockwise starting from `Up`.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Returns the direction represented by `byte`, or `None` if there isn't one.
+    ///
+    /// Accepts arrows (`^`, `>`, `v`, `<`), letters (`U`, `R`, `D`, `L`) and compass points (`N`, `E`, `S`, `W`).
+    pub fn try_from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b'^' | b'U' | b'N' => Some(Self::Up),
+            b'>' | b'R' | b'E' => Some(Self::Right),
+            b'v' | b'D' | b'S' => Some(Self::Down),
+            b'<' | b'L' | b'W' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// Returns the direction represented by `byte`.
+    ///
+    /// Accepts arrows (`^`, `>`, `v`, `<`), letters (`U`, `R`, `D`, `L`) and compass points (`N`, `E`, `S`, `W`).
+    ///
+    /// Panics if `byte` does not represent a direction.
+    #[inline]
+    #[track_caller]
+    pub fn from_byte(byte: u8) -> Self {
+        Self::try_from_byte(byte).expect("byte should represent a direction")
+    }
+
+    /// Returns the index of `self` in [`Dir4::ALL`].
+    #[inline(always)]
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the unit offset of one step in this direction.
+    #[inline]
+    pub fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Right => Point::new(1, 0),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// Returns the direction 90 degrees clockwise from `self`.
+    #[inline]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    /// Returns the direction 90 degrees anticlockwise from `self`.
+    #[inline]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    /// Returns the opposite direction to `self`.
+    #[inline]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    /// Returns whether `self` is `Left` or `Right`.
+    #[inline]
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    /// Returns whether `self` is `Up` or `Down`.
+    #[inline]
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+}
+
+impl TryFrom<u8> for Dir4 {
+    type Error = u8;
+
+    /// Calls [`Dir4::try_from_byte`], returning the byte as the error if it does not represent a direction.
+    fn try_from(byte: u8) -> Result<Self, Self::Error> {
+        Self::try_from_byte(byte).ok_or(byte)
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = char;
+
+    /// Calls [`Dir4::try_from_byte`], returning the character as the error if it does not represent a direction.
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        u8::try_from(ch)
+            .ok()
+            .and_then(Self::try_from_byte)
+            .ok_or(ch)
+    }
+}
+
+/// One of the 8 orthogonal and diagonal directions on a 2D grid, with `y` increasing downwards.
+///
+/// # Examples
+/// ```
+/// use aoc::{Dir4, Dir8, Point};
+///
+/// let dir = Dir8::from(Dir4::Up);
+///
+/// assert_eq!(dir.turn_right(), Dir8::UpRight);
+/// assert_eq!(dir.turn_left(), Dir8::UpLeft);
+/// assert_eq!(Dir8::UpRight.reverse(), Dir8::DownLeft);
+/// assert_eq!(Dir8::DownLeft.offset(), Point::new(-1, 1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// All 8 directions, clockwise starting from `Up`.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Returns the index of `self` in [`Dir8::ALL`].
+    #[inline(always)]
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the offset of one step in this direction.
+    #[inline]
+    pub fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::UpRight => Point::new(1, -1),
+            Self::Right => Point::new(1, 0),
+            Self::DownRight => Point::new(1, 1),
+            Self::Down => Point::new(0, 1),
+            Self::DownLeft => Point::new(-1, 1),
+            Self::Left => Point::new(-1, 0),
+            Self::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    /// Returns the direction 45 degrees clockwise from `self`.
+    #[inline]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Returns the direction 45 degrees anticlockwise from `self`.
+    #[inline]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Returns the opposite direction to `self`.
+    #[inline]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// Returns whether `self` is one of the 4 diagonal directions.
+    #[inline]
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    #[inline]
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir.index() * 2]
+    }
+}
+
+impl TryFrom<Dir8> for Dir4 {
+    type Error = Dir8;
+
+    /// Converts an orthogonal `Dir8`, returning diagonal directions as the error.
+    fn try_from(dir: Dir8) -> Result<Self, Self::Error> {
+        if dir.is_diagonal() {
+            Err(dir)
+        } else {
+            Ok(Self::ALL[dir.index() / 2])
+        }
+    }
+}
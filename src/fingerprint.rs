@@ -0,0 +1,27 @@
+use std::{fs, path::Path};
+
+/// Returns the 64-bit FNV-1a hash of `input`.
+pub(crate) fn checksum(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Formats a checksum the way it is stored in the `checksum` file and in `result.json`.
+pub(crate) fn to_hex(checksum: u64) -> String {
+    format!("{checksum:016x}")
+}
+
+/// Returns whether the answer stored in `out_path` was computed from an input other than the one at `input_path`.
+///
+/// An output directory with no stored checksum (never run, or unimplemented) is never stale.
+/// A stored checksum with a missing input file is always stale.
+pub(crate) fn is_stale(input_path: &Path, out_path: &Path) -> bool {
+    let Ok(stored) = fs::read_to_string(out_path.join("checksum")) else {
+        return false;
+    };
+    let Ok(input) = fs::read_to_string(input_path) else {
+        return true;
+    };
+    stored.trim() != to_hex(checksum(input.trim_end()))
+}
@@ -0,0 +1,275 @@
+use std::{collections::HashMap, ops::Range};
+
+mod cliques;
+mod order;
+
+pub use cliques::AdjacencyMatrix;
+pub use order::{
+    find_cycle, schedule, strongly_connected_components, topological_sort, topological_sort_by_key,
+    CycleError, Schedule,
+};
+
+/// A graph whose nodes are named by strings from the input, stored as adjacency lists.
+///
+/// Each name is given a dense `usize` id the first time it is seen, so nodes can be stored in `Vec`s and passed to
+/// searches, and turned back into names for answers. Edges can be directed or undirected, and carry a weight of
+/// type `W` (`()` for unweighted graphs). Adding the same edge twice adds it twice.
+///
+/// # Examples
+/// ```
+/// use aoc::{Graph, Parse};
+///
+/// // Haunted Wasteland: each node has a left and a right edge.
+/// let network = ["AAA = (BBB, CCC)", "BBB = (DDD, EEE)", "CCC = (ZZZ, GGG)", "ZZZ = (ZZZ, ZZZ)"];
+/// let mut graph = Graph::directed();
+/// for line in network {
+///     let mut parser = line.as_parser();
+///     let from = parser.before(" = (");
+///     graph.add_edge(from, parser.before(", "));
+///     graph.add_edge(from, parser.before(")"));
+/// }
+///
+/// let aaa = graph.id("AAA").unwrap();
+/// let right = graph.neighbours(aaa).nth(1).unwrap();
+///
+/// assert_eq!(graph.name(right), "CCC");
+/// assert_eq!(graph.len(), 7);
+/// assert_eq!(graph.edge_count(), 8);
+/// assert_eq!(graph.degree(graph.id("GGG").unwrap()), 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Graph<'a, W = ()> {
+    names: Vec<&'a str>,
+    ids: HashMap<&'a str, usize>,
+    adjacency: Vec<Vec<(usize, W)>>,
+    edges: usize,
+    directed: bool,
+}
+
+impl<'a> Graph<'a> {
+    /// Creates an empty unweighted graph whose edges go one way.
+    #[inline]
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    /// Creates an empty unweighted graph whose edges go both ways.
+    #[inline]
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+}
+
+impl<'a, W> Graph<'a, W> {
+    /// Creates an empty graph whose edges go one way and carry weights.
+    #[inline]
+    pub fn directed_weighted() -> Self {
+        Self::new(true)
+    }
+
+    /// Creates an empty graph whose edges go both ways and carry weights.
+    #[inline]
+    pub fn undirected_weighted() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            adjacency: Vec::new(),
+            edges: 0,
+            directed,
+        }
+    }
+
+    /// Returns whether edges go one way.
+    #[inline(always)]
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Returns the number of nodes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns whether the graph has no nodes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Returns the number of edges. Each undirected edge is counted once.
+    #[inline(always)]
+    pub fn edge_count(&self) -> usize {
+        self.edges
+    }
+
+    /// Returns the range of node ids, `0..self.len()`.
+    #[inline]
+    pub fn nodes(&self) -> Range<usize> {
+        0..self.len()
+    }
+
+    /// Returns the id of the node called `name`, adding it if there isn't one yet.
+    pub fn add_node(&mut self, name: &'a str) -> usize {
+        *self.ids.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            self.adjacency.push(Vec::new());
+            self.names.len() - 1
+        })
+    }
+
+    /// Returns the id of the node called `name`, or `None` if there isn't one.
+    #[inline]
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the name of node `id`.
+    ///
+    /// Panics if there is no node `id`.
+    #[inline]
+    #[track_caller]
+    pub fn name(&self, id: usize) -> &'a str {
+        self.names[id]
+    }
+
+    /// Returns the names of the nodes, indexed by id.
+    #[inline(always)]
+    pub fn names(&self) -> &[&'a str] {
+        &self.names
+    }
+
+    /// Adds an edge from `from` to `to` with the given weight, adding the nodes if needed, and returns their ids.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{search, Graph, Parse};
+    ///
+    /// let mut graph = Graph::undirected_weighted();
+    /// for line in ["London to Dublin = 464", "London to Belfast = 518", "Dublin to Belfast = 141"] {
+    ///     let mut parser = line.as_parser();
+    ///     let from = parser.before(" to ");
+    ///     let to = parser.before(" = ");
+    ///     graph.add_weighted_edge(from, to, parser.rest().parse_uw::<u32>());
+    /// }
+    ///
+    /// let london = graph.id("London").unwrap();
+    /// let found = search::dijkstra(london, |&id| graph.edges(id).to_vec(), |&id| graph.name(id) == "Belfast");
+    ///
+    /// assert_eq!(found.distance(), Some(518));
+    /// assert_eq!(graph.edges(graph.id("Dublin").unwrap()), [(0, 464), (2, 141)]);
+    /// ```
+    pub fn add_weighted_edge(&mut self, from: &'a str, to: &'a str, weight: W) -> (usize, usize)
+    where
+        W: Clone,
+    {
+        let (a, b) = (self.add_node(from), self.add_node(to));
+        self.link(a, b, weight);
+        (a, b)
+    }
+
+    /// Adds an edge between the nodes with ids `a` and `b`, with the given weight.
+    ///
+    /// Panics if either node does not exist.
+    #[track_caller]
+    pub fn link(&mut self, a: usize, b: usize, weight: W)
+    where
+        W: Clone,
+    {
+        if a.max(b) >= self.len() {
+            panic!("both nodes should exist");
+        }
+        if !self.directed && a != b {
+            self.adjacency[b].push((a, weight.clone()));
+        }
+        self.adjacency[a].push((b, weight));
+        self.edges += 1;
+    }
+
+    /// Returns an iterator over the ids of the nodes that node `id` has an edge to.
+    #[inline]
+    #[track_caller]
+    pub fn neighbours(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adjacency[id].iter().map(|&(to, _)| to)
+    }
+
+    /// Returns the edges from node `id`, as `(to, weight)` pairs.
+    #[inline]
+    #[track_caller]
+    pub fn edges(&self, id: usize) -> &[(usize, W)] {
+        &self.adjacency[id]
+    }
+
+    /// Returns the number of edges from node `id`.
+    #[inline]
+    #[track_caller]
+    pub fn degree(&self, id: usize) -> usize {
+        self.adjacency[id].len()
+    }
+
+    /// Returns whether there is an edge from node `a` to node `b`.
+    #[inline]
+    #[track_caller]
+    pub fn has_edge(&self, a: usize, b: usize) -> bool {
+        self.neighbours(a).any(|to| to == b)
+    }
+}
+
+impl<'a, W: Clone + Default> Graph<'a, W> {
+    /// Adds an edge from `from` to `to` with the default weight, adding the nodes if needed, and returns their ids.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{Graph, Parse};
+    ///
+    /// // LAN Party: undirected connections written as `a-b`.
+    /// let mut graph = Graph::undirected();
+    /// for line in ["kh-tc", "qp-kh", "de-cg", "ka-co", "yn-aq", "qp-ub", "cg-tb", "kh-ub"] {
+    ///     let mut parser = line.as_parser();
+    ///     graph.add_edge(parser.before("-"), parser.rest());
+    /// }
+    ///
+    /// let kh = graph.id("kh").unwrap();
+    /// let mut names: Vec<_> = graph.neighbours(kh).map(|id| graph.name(id)).collect();
+    /// names.sort();
+    ///
+    /// assert_eq!(names, ["qp", "tc", "ub"]);
+    /// assert!(graph.has_edge(graph.id("ub").unwrap(), kh));
+    /// assert_eq!(graph.edge_count(), 8);
+    /// ```
+    #[inline]
+    pub fn add_edge(&mut self, from: &'a str, to: &'a str) -> (usize, usize) {
+        self.add_weighted_edge(from, to, W::default())
+    }
+
+    /// Adds an edge from `from` to each of `to`, as in `x -> y, z`, adding the nodes if needed,
+    /// and returns the id of `from`.
+    pub fn add_edges(&mut self, from: &'a str, to: impl IntoIterator<Item = &'a str>) -> usize {
+        let a = self.add_node(from);
+        for name in to {
+            let b = self.add_node(name);
+            self.link(a, b, W::default());
+        }
+        a
+    }
+}
+
+impl<'a, W: Clone + Default> Extend<(&'a str, &'a str)> for Graph<'a, W> {
+    fn extend<I: IntoIterator<Item = (&'a str, &'a str)>>(&mut self, iter: I) {
+        for (from, to) in iter {
+            self.add_edge(from, to);
+        }
+    }
+}
+
+impl<'a, W: Clone> Extend<(&'a str, &'a str, W)> for Graph<'a, W> {
+    fn extend<I: IntoIterator<Item = (&'a str, &'a str, W)>>(&mut self, iter: I) {
+        for (from, to, weight) in iter {
+            self.add_weighted_edge(from, to, weight);
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use super::Graph;
+
+/// An undirected graph on the nodes `0..n`, stored as one bitset of neighbours per node.
+///
+/// Finds cliques: sets of nodes that are all connected to each other. Set operations on neighbourhoods work on 64
+/// nodes at a time, which makes these searches fast for the few thousand nodes puzzles usually have.
+///
+/// # Examples
+/// ```
+/// use aoc::AdjacencyMatrix;
+///
+/// let mut matrix = AdjacencyMatrix::new(5);
+/// for (a, b) in [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)] {
+///     matrix.connect(a, b);
+/// }
+///
+/// assert!(matrix.is_connected(3, 1));
+/// assert_eq!(matrix.degree(2), 3);
+/// assert_eq!(matrix.triangles(), [[0, 1, 2], [1, 2, 3]]);
+/// assert_eq!(matrix.maximal_cliques(), [vec![0, 1, 2], vec![1, 2, 3], vec![3, 4]]);
+/// assert_eq!(matrix.maximum_clique(), [0, 1, 2]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AdjacencyMatrix {
+    len: usize,
+    stride: usize,
+    words: Vec<u64>,
+}
+
+impl AdjacencyMatrix {
+    /// Creates an `AdjacencyMatrix` with `len` nodes and no edges.
+    pub fn new(len: usize) -> Self {
+        let stride = len.div_ceil(64);
+        Self {
+            len,
+            stride,
+            words: vec![0; len * stride],
+        }
+    }
+
+    /// Returns the number of nodes.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no nodes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds an edge between `a` and `b`. Edges from a node to itself are ignored.
+    ///
+    /// Panics if `a` or `b` is not less than `self.len()`.
+    #[inline]
+    #[track_caller]
+    pub fn connect(&mut self, a: usize, b: usize) {
+        if a.max(b) >= self.len {
+            panic!("both nodes should exist");
+        }
+        if a != b {
+            self.words[a * self.stride + b / 64] |= 1 << (b % 64);
+            self.words[b * self.stride + a / 64] |= 1 << (a % 64);
+        }
+    }
+
+    /// Returns whether there is an edge between `a` and `b`.
+    #[inline]
+    #[track_caller]
+    pub fn is_connected(&self, a: usize, b: usize) -> bool {
+        self.row(a)[b / 64] >> (b % 64) & 1 == 1
+    }
+
+    /// Returns the number of neighbours of `a`.
+    #[inline]
+    #[track_caller]
+    pub fn degree(&self, a: usize) -> usize {
+        count(self.row(a))
+    }
+
+    /// Returns an iterator over the neighbours of `a`, in increasing order.
+    #[inline]
+    #[track_caller]
+    pub fn neighbours(&self, a: usize) -> impl Iterator<Item = usize> + '_ {
+        ones(self.row(a))
+    }
+
+    /// Returns every maximal clique, that is, every clique that cannot be extended by another node,
+    /// using the Bron–Kerbosch algorithm with pivoting.
+    ///
+    /// Each clique is sorted, and the cliques are in lexicographic order. Isolated nodes are cliques of size 1.
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let mut cliques = Vec::new();
+        let mut best = 0;
+        self.bron_kerbosch(
+            &mut Vec::new(),
+            self.all(),
+            vec![0; self.stride],
+            &mut best,
+            false,
+            &mut |clique| cliques.push(clique),
+        );
+        cliques.sort_unstable();
+        cliques
+    }
+
+    /// Returns a largest clique, sorted. If there are several, returns one of them.
+    ///
+    /// Uses the Bron–Kerbosch algorithm with pivoting, skipping branches that cannot beat the largest clique so far.
+    pub fn maximum_clique(&self) -> Vec<usize> {
+        let mut largest = Vec::new();
+        let mut best = 0;
+        self.bron_kerbosch(
+            &mut Vec::new(),
+            self.all(),
+            vec![0; self.stride],
+            &mut best,
+            true,
+            &mut |clique| largest = clique,
+        );
+        largest
+    }
+
+    /// Returns every clique of exactly `k` nodes, each sorted, in lexicographic order.
+    pub fn cliques(&self, k: usize) -> Vec<Vec<usize>> {
+        let mut cliques = Vec::new();
+        self.extend(&mut Vec::with_capacity(k), self.all(), k, &mut cliques);
+        cliques
+    }
+
+    /// Returns every triangle, each sorted, in lexicographic order.
+    pub fn triangles(&self) -> Vec<[usize; 3]> {
+        let mut triangles = Vec::new();
+        for a in 0..self.len {
+            for b in ones(self.row(a)).filter(|&b| b > a) {
+                let common = and(self.row(a), self.row(b));
+                triangles.extend(ones(&common).filter(|&c| c > b).map(|c| [a, b, c]));
+            }
+        }
+        triangles
+    }
+
+    #[inline]
+    #[track_caller]
+    fn row(&self, a: usize) -> &[u64] {
+        if a >= self.len {
+            panic!("node should exist");
+        }
+        &self.words[a * self.stride..(a + 1) * self.stride]
+    }
+
+    /// Returns the set of every node.
+    fn all(&self) -> Vec<u64> {
+        let mut all = vec![u64::MAX; self.stride];
+        if !self.len.is_multiple_of(64) {
+            all[self.stride - 1] = (1 << (self.len % 64)) - 1;
+        }
+        all
+    }
+
+    /// Reports every maximal clique containing all of `r`, some of `p`, and none of `x`.
+    ///
+    /// When `prune` is set, only reports cliques larger than `best`, and skips branches that cannot beat it.
+    fn bron_kerbosch(
+        &self,
+        r: &mut Vec<usize>,
+        mut p: Vec<u64>,
+        mut x: Vec<u64>,
+        best: &mut usize,
+        prune: bool,
+        found: &mut impl FnMut(Vec<usize>),
+    ) {
+        let candidates = count(&p);
+        if prune && r.len() + candidates <= *best {
+            return;
+        }
+        if candidates == 0 {
+            if x.iter().all(|&w| w == 0) {
+                *best = (*best).max(r.len());
+                let mut clique = r.clone();
+                clique.sort_unstable();
+                found(clique);
+            }
+            return;
+        }
+        // Every maximal clique contains the pivot or a node that isn't its neighbour,
+        // so only those nodes need branching on.
+        let pivot = ones(&p)
+            .chain(ones(&x))
+            .max_by_key(|&u| count(&and(&p, self.row(u))))
+            .unwrap();
+        let branches: Vec<usize> = ones(&and_not(&p, self.row(pivot))).collect();
+        for v in branches {
+            r.push(v);
+            self.bron_kerbosch(
+                r,
+                and(&p, self.row(v)),
+                and(&x, self.row(v)),
+                best,
+                prune,
+                found,
+            );
+            r.pop();
+            p[v / 64] &= !(1 << (v % 64));
+            x[v / 64] |= 1 << (v % 64);
+        }
+    }
+
+    /// Adds every `k`-clique made of `clique` and nodes in `candidates` to `out`,
+    /// where every candidate is connected to all of `clique` and greater than its nodes.
+    fn extend(
+        &self,
+        clique: &mut Vec<usize>,
+        candidates: Vec<u64>,
+        k: usize,
+        out: &mut Vec<Vec<usize>>,
+    ) {
+        if clique.len() == k {
+            out.push(clique.clone());
+            return;
+        }
+        if clique.len() + count(&candidates) < k {
+            return;
+        }
+        for v in ones(&candidates) {
+            let mut next = and(&candidates, self.row(v));
+            // Keep only candidates greater than `v`, so each clique is found once, in increasing order.
+            for (i, word) in next.iter_mut().enumerate().take(v / 64 + 1) {
+                if i < v / 64 {
+                    *word = 0;
+                } else {
+                    *word &= !(u64::MAX >> (63 - v % 64));
+                }
+            }
+            clique.push(v);
+            self.extend(clique, next, k, out);
+            clique.pop();
+        }
+    }
+}
+
+impl<'a, W> Graph<'a, W> {
+    /// Returns the graph as an [`AdjacencyMatrix`], treating every edge as undirected.
+    pub fn adjacency_matrix(&self) -> AdjacencyMatrix {
+        let mut matrix = AdjacencyMatrix::new(self.len());
+        for a in self.nodes() {
+            for b in self.neighbours(a) {
+                matrix.connect(a, b);
+            }
+        }
+        matrix
+    }
+
+    /// Returns every maximal clique of node ids, treating every edge as undirected.
+    ///
+    /// See [`AdjacencyMatrix::maximal_cliques`].
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        self.adjacency_matrix().maximal_cliques()
+    }
+
+    /// Returns a largest clique of node ids, treating every edge as undirected.
+    ///
+    /// See [`AdjacencyMatrix::maximum_clique`].
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{Graph, Parse};
+    ///
+    /// // LAN Party: sets of three computers that include one starting with `t`, and the largest set.
+    /// let connections = "kh-tc qp-kh de-cg ka-co yn-aq qp-ub cg-tb vc-aq tb-ka wh-tc yn-cg kh-ub ta-co de-co tc-td tb-wq \
+    ///                    wh-td ta-ka td-qp aq-cg wq-ub ub-vc de-ta wq-aq wq-vc wh-yn ka-de kh-ta co-tc wh-qp tb-vc td-yn";
+    /// let mut graph = Graph::undirected();
+    /// for pair in connections.split(' ') {
+    ///     let mut parser = pair.as_parser();
+    ///     graph.add_edge(parser.before("-"), parser.rest());
+    /// }
+    ///
+    /// let triangles = graph.triangles();
+    /// assert_eq!(triangles.len(), 12);
+    /// assert_eq!(triangles.iter().filter(|t| t.iter().any(|&id| graph.name(id).starts_with('t'))).count(), 7);
+    ///
+    /// let mut party: Vec<_> = graph.maximum_clique().into_iter().map(|id| graph.name(id)).collect();
+    /// party.sort();
+    /// assert_eq!(party.join(","), "co,de,ka,ta");
+    /// ```
+    pub fn maximum_clique(&self) -> Vec<usize> {
+        self.adjacency_matrix().maximum_clique()
+    }
+
+    /// Returns every clique of exactly `k` node ids, treating every edge as undirected.
+    ///
+    /// See [`AdjacencyMatrix::cliques`].
+    pub fn cliques(&self, k: usize) -> Vec<Vec<usize>> {
+        self.adjacency_matrix().cliques(k)
+    }
+
+    /// Returns every triangle of node ids, treating every edge as undirected.
+    ///
+    /// See [`AdjacencyMatrix::triangles`].
+    pub fn triangles(&self) -> Vec<[usize; 3]> {
+        self.adjacency_matrix().triangles()
+    }
+}
+
+fn ones(words: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    words.iter().enumerate().flat_map(|(i, &word)| {
+        let mut word = word;
+        std::iter::from_fn(move || {
+            (word != 0).then(|| {
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                i * 64 + bit
+            })
+        })
+    })
+}
+
+fn count(words: &[u64]) -> usize {
+    words.iter().map(|w| w.count_ones() as usize).sum()
+}
+
+fn and(a: &[u64], b: &[u64]) -> Vec<u64> {
+    a.iter().zip(b).map(|(a, b)| a & b).collect()
+}
+
+fn and_not(a: &[u64], b: &[u64]) -> Vec<u64> {
+    a.iter().zip(b).map(|(a, b)| a & !b).collect()
+}
@@ -0,0 +1,419 @@
+use std::{cmp::Reverse, collections::BinaryHeap, fmt};
+
+use super::Graph;
+
+/// The error returned when a cycle prevents the nodes of a graph from being put in order.
+///
+/// `N` is the type used to identify nodes: `usize` ids for [`topological_sort`] and the other free functions,
+/// and names for the methods on [`Graph`].
+///
+/// # Examples
+/// ```
+/// use aoc::Graph;
+///
+/// let mut graph = Graph::directed();
+/// graph.extend([("a", "b"), ("b", "c"), ("c", "d"), ("d", "b")]);
+/// let error = graph.topological_sort().unwrap_err();
+///
+/// assert_eq!(error.cycle().len(), 3);
+/// assert_eq!(error.to_string(), "graph contains a cycle: b -> c -> d -> b");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CycleError<N = usize> {
+    cycle: Vec<N>,
+}
+
+impl<N> CycleError<N> {
+    /// Returns the nodes around the cycle, in order, each once.
+    #[inline(always)]
+    pub fn cycle(&self) -> &[N] {
+        &self.cycle
+    }
+
+    /// Returns the error with each node replaced by `f(node)`.
+    pub fn map<M>(self, f: impl FnMut(N) -> M) -> CycleError<M> {
+        CycleError {
+            cycle: self.cycle.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Rotates the cycle to start from its smallest node, so that the same cycle is always reported the same way.
+    fn normalised(mut self) -> Self
+    where
+        N: Ord,
+    {
+        let start = (0..self.cycle.len())
+            .min_by(|&a, &b| self.cycle[a].cmp(&self.cycle[b]))
+            .unwrap_or(0);
+        self.cycle.rotate_left(start);
+        self
+    }
+}
+
+impl<N: fmt::Display> fmt::Display for CycleError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("graph contains a cycle: ")?;
+        for node in &self.cycle {
+            write!(f, "{node} -> ")?;
+        }
+        match self.cycle.first() {
+            Some(first) => write!(f, "{first}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> std::error::Error for CycleError<N> {}
+
+/// When each node started and finished in a [`schedule`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    order: Vec<usize>,
+    start: Vec<u64>,
+    finish: Vec<u64>,
+}
+
+impl Schedule {
+    /// Returns the time at which the last node finished.
+    #[inline]
+    pub fn time(&self) -> u64 {
+        self.finish.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Returns the nodes in the order they finished.
+    #[inline(always)]
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns the time at which node `id` started.
+    #[inline]
+    #[track_caller]
+    pub fn start(&self, id: usize) -> u64 {
+        self.start[id]
+    }
+
+    /// Returns the time at which node `id` finished.
+    #[inline]
+    #[track_caller]
+    pub fn finish(&self, id: usize) -> u64 {
+        self.finish[id]
+    }
+}
+
+/// Returns the nodes `0..n` ordered so that every node comes before its successors, using Kahn's algorithm.
+///
+/// Where there is a choice, the node with the smallest id comes first.
+///
+/// # Examples
+/// ```
+/// let successors = [vec![2], vec![0, 2], vec![], vec![1]];
+///
+/// assert_eq!(aoc::topological_sort(4, |i| successors[i].clone()), Ok(vec![3, 1, 0, 2]));
+/// ```
+pub fn topological_sort<I>(
+    n: usize,
+    successors: impl FnMut(usize) -> I,
+) -> Result<Vec<usize>, CycleError>
+where
+    I: IntoIterator<Item = usize>,
+{
+    topological_sort_by_key(n, successors, |i| i)
+}
+
+/// Returns the nodes `0..n` ordered so that every node comes before its successors, using Kahn's algorithm.
+///
+/// Where there is a choice, the node with the smallest key comes first, which gives the lexicographically smallest
+/// order by key.
+pub fn topological_sort_by_key<I, K: Ord>(
+    n: usize,
+    successors: impl FnMut(usize) -> I,
+    mut key: impl FnMut(usize) -> K,
+) -> Result<Vec<usize>, CycleError>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let adjacency = collect(n, successors);
+    let mut indegree = indegrees(&adjacency);
+    let mut ready: BinaryHeap<_> = (0..n)
+        .filter(|&i| indegree[i] == 0)
+        .map(|i| Reverse((key(i), i)))
+        .collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(Reverse((_, i))) = ready.pop() {
+        order.push(i);
+        for &j in &adjacency[i] {
+            indegree[j] -= 1;
+            if indegree[j] == 0 {
+                ready.push(Reverse((key(j), j)));
+            }
+        }
+    }
+    if order.len() < n {
+        return Err(remaining_cycle(&adjacency, &indegree));
+    }
+    Ok(order)
+}
+
+/// Works through the nodes `0..n` with `workers` workers in parallel, where each node takes `duration(id)` time and
+/// cannot start until all of its predecessors have finished.
+///
+/// Whenever a worker is free, it starts the available node with the smallest key. Nodes that finish at the same time
+/// are ordered by key.
+///
+/// Panics if `workers` is zero.
+///
+/// # Examples
+/// ```
+/// use aoc::Graph;
+///
+/// // The Sum of Its Parts: steps are lettered, and step `X` takes `X - 'A' + 1` seconds.
+/// let mut steps = Graph::directed();
+/// steps.extend([("C", "A"), ("C", "F"), ("A", "B"), ("A", "D"), ("B", "E"), ("D", "E"), ("F", "E")]);
+///
+/// let order = steps.topological_sort_by_name().unwrap();
+/// assert_eq!(order.iter().map(|&id| steps.name(id)).collect::<String>(), "CABDFE");
+///
+/// let schedule = steps.schedule(2, |name| (name.as_bytes()[0] - b'A' + 1) as u64).unwrap();
+/// assert_eq!(schedule.time(), 15);
+/// assert_eq!(schedule.order().iter().map(|&id| steps.name(id)).collect::<String>(), "CABFDE");
+/// assert_eq!(schedule.start(steps.id("E").unwrap()), 10);
+/// ```
+#[track_caller]
+pub fn schedule<I, K: Ord>(
+    n: usize,
+    successors: impl FnMut(usize) -> I,
+    workers: usize,
+    mut duration: impl FnMut(usize) -> u64,
+    mut key: impl FnMut(usize) -> K,
+) -> Result<Schedule, CycleError>
+where
+    I: IntoIterator<Item = usize>,
+{
+    if workers == 0 {
+        panic!("there should be at least one worker");
+    }
+    let adjacency = collect(n, successors);
+    let mut indegree = indegrees(&adjacency);
+    let mut ready: BinaryHeap<_> = (0..n)
+        .filter(|&i| indegree[i] == 0)
+        .map(|i| Reverse((key(i), i)))
+        .collect();
+    let mut running = BinaryHeap::new();
+    let mut schedule = Schedule {
+        order: Vec::with_capacity(n),
+        start: vec![0; n],
+        finish: vec![0; n],
+    };
+    let mut time = 0;
+    loop {
+        while running.len() < workers {
+            let Some(Reverse((k, i))) = ready.pop() else {
+                break;
+            };
+            schedule.start[i] = time;
+            running.push(Reverse((time + duration(i), k, i)));
+        }
+        let Some(&Reverse((next, _, _))) = running.peek() else {
+            break;
+        };
+        time = next;
+        while let Some(&Reverse((finish, _, i))) = running.peek() {
+            if finish > time {
+                break;
+            }
+            running.pop();
+            schedule.finish[i] = time;
+            schedule.order.push(i);
+            for &j in &adjacency[i] {
+                indegree[j] -= 1;
+                if indegree[j] == 0 {
+                    ready.push(Reverse((key(j), j)));
+                }
+            }
+        }
+    }
+    if schedule.order.len() < n {
+        return Err(remaining_cycle(&adjacency, &indegree));
+    }
+    Ok(schedule)
+}
+
+/// Returns a cycle among the nodes `0..n`, or `None` if there are none.
+///
+/// The cycle starts from its smallest node.
+pub fn find_cycle<I>(n: usize, successors: impl FnMut(usize) -> I) -> Option<Vec<usize>>
+where
+    I: IntoIterator<Item = usize>,
+{
+    topological_sort(n, successors).err().map(|e| e.cycle)
+}
+
+/// Returns the strongly connected components of the nodes `0..n`, using Tarjan's algorithm.
+///
+/// Every node is in exactly one component. A component comes before every component with an edge into it,
+/// so the components are in reverse topological order.
+///
+/// # Examples
+/// ```
+/// let successors = [vec![1], vec![2], vec![0, 3], vec![4], vec![3], vec![]];
+/// let components = aoc::strongly_connected_components(6, |i| successors[i].clone());
+///
+/// assert_eq!(components, [vec![3, 4], vec![0, 1, 2], vec![5]]);
+/// ```
+pub fn strongly_connected_components<I>(
+    n: usize,
+    successors: impl FnMut(usize) -> I,
+) -> Vec<Vec<usize>>
+where
+    I: IntoIterator<Item = usize>,
+{
+    const UNVISITED: usize = usize::MAX;
+    let adjacency = collect(n, successors);
+    let mut index = vec![UNVISITED; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut next = 0;
+    // Each frame is a node being visited, and how many of its successors have been looked at.
+    let mut frames: Vec<(usize, usize)> = Vec::new();
+    for root in 0..n {
+        if index[root] != UNVISITED {
+            continue;
+        }
+        frames.push((root, 0));
+        while let Some(&(i, seen)) = frames.last() {
+            if index[i] == UNVISITED {
+                index[i] = next;
+                low[i] = next;
+                next += 1;
+                stack.push(i);
+                on_stack[i] = true;
+            }
+            if let Some(&j) = adjacency[i].get(seen) {
+                frames.last_mut().unwrap().1 += 1;
+                if index[j] == UNVISITED {
+                    frames.push((j, 0));
+                } else if on_stack[j] {
+                    low[i] = low[i].min(index[j]);
+                }
+                continue;
+            }
+            frames.pop();
+            if let Some(&(parent, _)) = frames.last() {
+                low[parent] = low[parent].min(low[i]);
+            }
+            if low[i] == index[i] {
+                let at = stack.iter().rposition(|&k| k == i).unwrap();
+                let mut component = stack.split_off(at);
+                for &k in &component {
+                    on_stack[k] = false;
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+impl<'a, W> Graph<'a, W> {
+    /// Returns the node ids ordered so that every node comes before the nodes it has edges to.
+    ///
+    /// Where there is a choice, the node with the smallest id comes first. See [`topological_sort`].
+    pub fn topological_sort(&self) -> Result<Vec<usize>, CycleError<&'a str>> {
+        topological_sort(self.len(), |i| self.neighbours(i)).map_err(|e| self.named(e))
+    }
+
+    /// Returns the node ids ordered so that every node comes before the nodes it has edges to.
+    ///
+    /// Where there is a choice, the node whose name comes first alphabetically comes first.
+    pub fn topological_sort_by_name(&self) -> Result<Vec<usize>, CycleError<&'a str>> {
+        topological_sort_by_key(self.len(), |i| self.neighbours(i), |i| self.name(i))
+            .map_err(|e| self.named(e))
+    }
+
+    /// Works through the nodes with `workers` workers in parallel, where each node takes `duration(name)` time and
+    /// cannot start until every node with an edge to it has finished.
+    ///
+    /// Free workers start available nodes in alphabetical order. See [`schedule`].
+    pub fn schedule(
+        &self,
+        workers: usize,
+        mut duration: impl FnMut(&'a str) -> u64,
+    ) -> Result<Schedule, CycleError<&'a str>> {
+        schedule(
+            self.len(),
+            |i| self.neighbours(i),
+            workers,
+            |i| duration(self.name(i)),
+            |i| self.name(i),
+        )
+        .map_err(|e| self.named(e))
+    }
+
+    /// Returns a cycle of node ids, or `None` if there are none.
+    ///
+    /// Each undirected edge counts as a cycle of two nodes.
+    pub fn find_cycle(&self) -> Option<Vec<usize>> {
+        find_cycle(self.len(), |i| self.neighbours(i))
+    }
+
+    /// Returns the strongly connected components of the graph, as lists of node ids.
+    ///
+    /// See [`strongly_connected_components`].
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        strongly_connected_components(self.len(), |i| self.neighbours(i))
+    }
+
+    fn named(&self, error: CycleError) -> CycleError<&'a str> {
+        error.map(|i| self.name(i))
+    }
+}
+
+fn collect<I>(n: usize, mut successors: impl FnMut(usize) -> I) -> Vec<Vec<usize>>
+where
+    I: IntoIterator<Item = usize>,
+{
+    (0..n)
+        .map(|i| successors(i).into_iter().collect())
+        .collect()
+}
+
+fn indegrees(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let mut indegree = vec![0; adjacency.len()];
+    for &j in adjacency.iter().flatten() {
+        indegree[j] += 1;
+    }
+    indegree
+}
+
+/// Finds a cycle among the nodes left with predecessors after Kahn's algorithm gets stuck.
+///
+/// Every such node has a predecessor that is also left, so walking backwards must eventually repeat a node.
+fn remaining_cycle(adjacency: &[Vec<usize>], indegree: &[usize]) -> CycleError {
+    let n = adjacency.len();
+    let mut predecessor = vec![usize::MAX; n];
+    for (i, successors) in adjacency.iter().enumerate() {
+        if indegree[i] > 0 {
+            for &j in successors {
+                predecessor[j] = i;
+            }
+        }
+    }
+    let mut seen = vec![false; n];
+    let mut node = (0..n).find(|&i| indegree[i] > 0).unwrap();
+    while !seen[node] {
+        seen[node] = true;
+        node = predecessor[node];
+    }
+    let mut cycle = vec![node];
+    let mut back = predecessor[node];
+    while back != node {
+        cycle.push(back);
+        back = predecessor[back];
+    }
+    cycle.reverse();
+    CycleError { cycle }.normalised()
+}
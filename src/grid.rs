@@ -0,0 +1,441 @@
+use std::{
+    fmt, iter,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{parse::FromStrUnwrap, Point};
+
+mod bits;
+mod regions;
+mod sparse;
+mod view;
+
+pub use bits::{BitGrid, NeighbourCounts};
+pub use regions::{Connectivity, Region, Regions};
+pub use sparse::{SparseGrid, Tiled};
+pub use view::{GridView, Symmetry};
+
+/// A rectangular grid of cells, stored in row-major order.
+///
+/// Usually built from an `Input` where each line is a row and each byte is a cell.
+/// Since `Input` yields its lines when iterated, the constructors accept either an `Input`
+/// or any other iterator of lines.
+///
+/// Cells can be indexed by anything implementing [`GridIndex`]: a `(usize, usize)` pair of `(x, y)`, or a [`Point`].
+/// Indexing with `[]` panics when out of bounds, while [`Grid::get`] returns `None`.
+///
+/// # Examples
+/// ```
+/// use aoc::{Grid, Point};
+///
+/// let grid = Grid::from_input("#.S\n..#".lines());
+///
+/// assert_eq!(grid.width(), 3);
+/// assert_eq!(grid.height(), 2);
+/// assert_eq!(grid[(2, 1)], b'#');
+/// assert_eq!(grid.get(Point::new(-1, 0)), None);
+/// assert_eq!(grid.position(&b'S'), Some(Point::new(2, 0)));
+/// assert_eq!(grid.to_string(), "#.S\n..#");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<u8> {
+    /// Creates a grid of bytes from the lines of an `Input`, or any other iterator of lines.
+    ///
+    /// Panics if the lines are not all the same length.
+    #[track_caller]
+    pub fn from_input<I, S>(input: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::from_input_map(input, |b| b)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a `width` by `height` grid, calling `f` with the position of each cell in row-major order.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i64, y as i64)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid from a `Vec` of cells in row-major order.
+    ///
+    /// Panics if the length of `cells` is not `width * height`.
+    #[track_caller]
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        if cells.len() != width * height {
+            panic!("`cells` should have length `width * height`");
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid from the lines of an `Input`, or any other iterator of lines,
+    /// mapping each byte to a cell with `f`.
+    ///
+    /// Panics if the lines are not all the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::Grid;
+    ///
+    /// let grid = Grid::from_input_map("#.\n.#".lines(), |b| b == b'#');
+    ///
+    /// assert!(grid[(0, 0)]);
+    /// assert!(!grid[(1, 0)]);
+    /// ```
+    #[track_caller]
+    pub fn from_input_map<I, S>(input: I, mut f: impl FnMut(u8) -> T) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input {
+            let line = line.as_ref().as_bytes();
+            if *width.get_or_insert(line.len()) != line.len() {
+                panic!("lines should all have the same length");
+            }
+            cells.extend(line.iter().map(|&b| f(b)));
+            height += 1;
+        }
+        Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid from the lines of an `Input`, or any other iterator of lines,
+    /// parsing each character into a cell.
+    ///
+    /// Requires that `T: FromStr` and `<T as FromStr>::Err: Debug`.
+    ///
+    /// Panics if the lines are not all the same length, or if a cell fails to parse.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::Grid;
+    ///
+    /// let grid = Grid::<u32>::from_input_parse("123\n456".lines());
+    ///
+    /// assert_eq!(grid[(1, 1)], 5);
+    /// ```
+    #[track_caller]
+    pub fn from_input_parse<I, S>(input: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        T: FromStrUnwrap,
+    {
+        Grid::from_input(input).map(|&b| T::parse(char::from(b).encode_utf8(&mut [0; 4])))
+    }
+
+    /// Returns the number of columns in the grid.
+    #[inline(always)]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows in the grid.
+    #[inline(always)]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cells of the grid in row-major order.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Returns the cells of the grid in row-major order, mutably.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Returns the cells of the grid in row-major order, consuming `self`.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.cells
+    }
+
+    /// Returns whether `index` is inside the grid.
+    #[inline]
+    pub fn in_bounds(&self, index: impl GridIndex) -> bool {
+        self.offset(index).is_some()
+    }
+
+    /// Returns a reference to the cell at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: impl GridIndex) -> Option<&T> {
+        self.offset(index).map(|i| &self.cells[i])
+    }
+
+    /// Returns a mutable reference to the cell at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: impl GridIndex) -> Option<&mut T> {
+        self.offset(index).map(|i| &mut self.cells[i])
+    }
+
+    /// Returns a reference to the cell at `index`, without bounds checking.
+    ///
+    /// # Safety
+    /// `index` must be inside the grid, as reported by [`Grid::in_bounds`].
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: impl GridIndex) -> &T {
+        let (x, y) = index.to_xy().unwrap_unchecked();
+        self.cells.get_unchecked(y * self.width + x)
+    }
+
+    /// Returns a mutable reference to the cell at `index`, without bounds checking.
+    ///
+    /// # Safety
+    /// `index` must be inside the grid, as reported by [`Grid::in_bounds`].
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, index: impl GridIndex) -> &mut T {
+        let (x, y) = index.to_xy().unwrap_unchecked();
+        self.cells.get_unchecked_mut(y * self.width + x)
+    }
+
+    /// Returns row `y` as a slice.
+    ///
+    /// Panics if `y` is at least the height of the grid.
+    #[inline]
+    #[track_caller]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns row `y` as a mutable slice.
+    ///
+    /// Panics if `y` is at least the height of the grid.
+    #[inline]
+    #[track_caller]
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns an iterator over the rows of the grid, from top to bottom.
+    #[inline]
+    pub fn rows(&self) -> slice::ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// Returns an iterator over the cells in column `x`, from top to bottom.
+    ///
+    /// Panics if `x` is at least the width of the grid.
+    #[inline]
+    #[track_caller]
+    pub fn column(&self, x: usize) -> iter::StepBy<slice::Iter<'_, T>> {
+        if x >= self.width {
+            panic!("column index out of bounds");
+        }
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Returns an iterator over the columns of the grid, from left to right.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::Grid;
+    ///
+    /// let grid = Grid::from_input("ab\ncd".lines());
+    /// let columns: Vec<Vec<u8>> = grid.columns().map(|c| c.copied().collect()).collect();
+    ///
+    /// assert_eq!(columns, [b"ac", b"bd"]);
+    /// ```
+    pub fn columns(&self) -> impl Iterator<Item = iter::StepBy<slice::Iter<'_, T>>> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Returns an iterator over the positions of all cells in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height as i64).flat_map(move |y| (0..width as i64).map(move |x| Point::new(x, y)))
+    }
+
+    /// Returns an iterator over the positions and values of all cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// Returns the position of the first cell in row-major order equal to `value`.
+    #[inline]
+    pub fn position(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    /// Returns an iterator over the positions of all cells equal to `value`, in row-major order.
+    pub fn positions<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |&(_, cell)| cell == value)
+            .map(|(p, _)| p)
+    }
+
+    /// Returns the position of the first cell in row-major order satisfying `predicate`.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        let i = self.cells.iter().position(predicate)?;
+        Some(self.point(i))
+    }
+
+    /// Returns an iterator over the orthogonally adjacent positions of `point` that are inside the grid,
+    /// in the order of [`Dir4::ALL`](crate::Dir4::ALL).
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{Grid, Point};
+    ///
+    /// let grid = Grid::new(3, 3, b'.');
+    ///
+    /// assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+    /// assert_eq!(grid.neighbours4(Point::new(0, 0)).count(), 2);
+    /// assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+    /// ```
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        point
+            .neighbours4()
+            .into_iter()
+            .filter(move |&p| is_in_bounds(p, width, height))
+    }
+
+    /// Returns an iterator over the orthogonally and diagonally adjacent positions of `point` that are inside the grid,
+    /// in the order of [`Dir8::ALL`](crate::Dir8::ALL).
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        point
+            .neighbours8()
+            .into_iter()
+            .filter(move |&p| is_in_bounds(p, width, height))
+    }
+
+    /// Returns a new grid of the same size, with `f` applied to each cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    #[inline]
+    fn offset(&self, index: impl GridIndex) -> Option<usize> {
+        let (x, y) = index.to_xy()?;
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    #[inline]
+    fn point(&self, offset: usize) -> Point {
+        Point::new((offset % self.width) as i64, (offset / self.width) as i64)
+    }
+}
+
+impl<T, I: GridIndex> Index<I> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).expect("grid index out of bounds")
+    }
+}
+
+impl<T, I: GridIndex> IndexMut<I> for Grid<T> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        self.get_mut(index).expect("grid index out of bounds")
+    }
+}
+
+impl fmt::Display for Grid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(self.rows(), f, |&b| char::from(b))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(self.rows(), f, |&ch| ch)
+    }
+}
+
+fn is_in_bounds(point: Point, width: usize, height: usize) -> bool {
+    point.to_xy().is_some_and(|(x, y)| x < width && y < height)
+}
+
+fn write_rows<'a, T: 'a, R: IntoIterator<Item = &'a T>>(
+    rows: impl Iterator<Item = R>,
+    f: &mut fmt::Formatter<'_>,
+    to_char: impl Fn(&T) -> char,
+) -> fmt::Result {
+    for (y, row) in rows.enumerate() {
+        if y > 0 {
+            writeln!(f)?;
+        }
+        for cell in row {
+            write!(f, "{}", to_char(cell))?;
+        }
+    }
+    Ok(())
+}
+
+/// A type that can be used to index into a [`Grid`].
+pub trait GridIndex: Copy {
+    /// Returns the `(x, y)` coordinates of `self`, or `None` if either is negative.
+    fn to_xy(self) -> Option<(usize, usize)>;
+}
+
+impl GridIndex for (usize, usize) {
+    #[inline(always)]
+    fn to_xy(self) -> Option<(usize, usize)> {
+        Some(self)
+    }
+}
+
+impl GridIndex for Point {
+    #[inline]
+    fn to_xy(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
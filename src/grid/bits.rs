@@ -0,0 +1,440 @@
+use std::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
+
+use super::{Grid, GridIndex};
+use crate::{Dir4, Point};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A rectangular grid of booleans, with each row packed into 64-bit words.
+///
+/// Whole-grid operations such as shifts, bitwise operators and neighbour counts work on 64 cells at a time,
+/// which makes it much faster than a `Grid<bool>` for cellular automata and reachability steps.
+/// Cells shifted off the edge of the grid are discarded.
+///
+/// # Examples
+/// ```
+/// use aoc::{BitGrid, Dir4};
+///
+/// let walls = BitGrid::from_input_map(["..#", "...", "#.."], |b| b == b'#');
+/// let mut reached = BitGrid::new(3, 3);
+/// reached.set((0, 0), true);
+/// for _ in 0..2 {
+///     reached = reached.spread4() & !&walls;
+/// }
+///
+/// assert_eq!(reached.count_ones(), 4);
+/// assert_eq!(reached.to_string(), "##.\n##.\n...");
+/// assert_eq!(reached.shift(Dir4::Right).to_string(), ".##\n.##\n...");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    stride: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Creates a `width` by `height` grid with every cell unset.
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(BITS);
+        Self {
+            width,
+            height,
+            stride,
+            words: vec![0; stride * height],
+        }
+    }
+
+    /// Creates a grid from the lines of an `Input`, or any other iterator of lines,
+    /// setting each cell for which `f` returns `true` for its byte.
+    ///
+    /// Panics if the lines are not all the same length.
+    #[track_caller]
+    pub fn from_input_map<I, S>(input: I, f: impl FnMut(u8) -> bool) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::from(&Grid::from_input_map(input, f))
+    }
+
+    /// Returns the number of columns in the grid.
+    #[inline(always)]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows in the grid.
+    #[inline(always)]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns whether `index` is inside the grid.
+    #[inline]
+    pub fn in_bounds(&self, index: impl GridIndex) -> bool {
+        index
+            .to_xy()
+            .is_some_and(|(x, y)| x < self.width && y < self.height)
+    }
+
+    /// Returns whether the cell at `index` is set, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: impl GridIndex) -> Option<bool> {
+        let (x, y) = index.to_xy()?;
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(self.words[y * self.stride + x / BITS] >> (x % BITS) & 1 == 1)
+    }
+
+    /// Returns whether the cell at `index` is set.
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn contains(&self, index: impl GridIndex) -> bool {
+        self.get(index).expect("grid index out of bounds")
+    }
+
+    /// Sets or unsets the cell at `index`.
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn set(&mut self, index: impl GridIndex, value: bool) {
+        let (x, y) = index
+            .to_xy()
+            .filter(|&(x, y)| x < self.width && y < self.height)
+            .expect("grid index out of bounds");
+        let word = &mut self.words[y * self.stride + x / BITS];
+        let bit = 1 << (x % BITS);
+        if value {
+            *word |= bit;
+        } else {
+            *word &= !bit;
+        }
+    }
+
+    /// Unsets every cell.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Returns the number of set cells.
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns whether no cells are set.
+    #[inline]
+    pub fn is_clear(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Returns an iterator over the positions of the set cells, in row-major order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let (y, x0) = (i / self.stride, (i % self.stride) * BITS);
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let x = x0 + word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(Point::new(x as i64, y as i64))
+            })
+        })
+    }
+
+    /// Returns a copy of the grid with every set cell moved one step in direction `dir`.
+    pub fn shift(&self, dir: Dir4) -> Self {
+        let mut shifted = Self::new(self.width, self.height);
+        let stride = self.stride;
+        if stride == 0 {
+            return shifted;
+        }
+        match dir {
+            Dir4::Up if self.height > 0 => {
+                shifted.words[..stride * (self.height - 1)].copy_from_slice(&self.words[stride..]);
+            }
+            Dir4::Down if self.height > 0 => {
+                shifted.words[stride..].copy_from_slice(&self.words[..stride * (self.height - 1)]);
+            }
+            Dir4::Left => {
+                for (dst, src) in shifted
+                    .words
+                    .chunks_exact_mut(stride)
+                    .zip(self.words.chunks_exact(stride))
+                {
+                    for i in 0..stride {
+                        let carry = src.get(i + 1).map_or(0, |&w| w << (BITS - 1));
+                        dst[i] = src[i] >> 1 | carry;
+                    }
+                }
+            }
+            Dir4::Right => {
+                for (dst, src) in shifted
+                    .words
+                    .chunks_exact_mut(stride)
+                    .zip(self.words.chunks_exact(stride))
+                {
+                    for i in 0..stride {
+                        let carry = if i > 0 { src[i - 1] >> (BITS - 1) } else { 0 };
+                        dst[i] = src[i] << 1 | carry;
+                    }
+                }
+                shifted.mask_padding();
+            }
+            _ => {}
+        }
+        shifted
+    }
+
+    /// Returns a copy of the grid with every cell orthogonally adjacent to a set cell also set.
+    pub fn spread4(&self) -> Self {
+        let mut spread = self.clone();
+        for dir in Dir4::ALL {
+            spread |= &self.shift(dir);
+        }
+        spread
+    }
+
+    /// Returns a copy of the grid with every cell orthogonally or diagonally adjacent to a set cell also set.
+    pub fn spread8(&self) -> Self {
+        let horizontal = self | &self.shift(Dir4::Left) | &self.shift(Dir4::Right);
+        &horizontal | &horizontal.shift(Dir4::Up) | &horizontal.shift(Dir4::Down)
+    }
+
+    /// Counts the set orthogonal neighbours of every cell.
+    pub fn neighbour_counts4(&self) -> NeighbourCounts {
+        let mut counts = NeighbourCounts::new(self.width, self.height);
+        for dir in Dir4::ALL {
+            counts.add(&self.shift(dir));
+        }
+        counts
+    }
+
+    /// Counts the set orthogonal and diagonal neighbours of every cell.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::BitGrid;
+    ///
+    /// // One step of Conway's Game of Life.
+    /// let blinker = BitGrid::from_input_map([".....", "..#..", "..#..", "..#..", "....."], |b| b == b'#');
+    /// let counts = blinker.neighbour_counts8();
+    /// let next = &counts.equal_to(3) | &(&counts.equal_to(2) & &blinker);
+    ///
+    /// assert_eq!(next.to_string(), ".....\n.....\n.###.\n.....\n.....");
+    /// ```
+    pub fn neighbour_counts8(&self) -> NeighbourCounts {
+        let mut counts = NeighbourCounts::new(self.width, self.height);
+        let left = self.shift(Dir4::Left);
+        let right = self.shift(Dir4::Right);
+        for row in [&left, self, &right] {
+            counts.add(&row.shift(Dir4::Up));
+            counts.add(&row.shift(Dir4::Down));
+        }
+        counts.add(&left);
+        counts.add(&right);
+        counts
+    }
+
+    /// Copies the grid into a `Grid<bool>`.
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid::from_fn(self.width, self.height, |p| self.contains(p))
+    }
+
+    fn mask_padding(&mut self) {
+        let used = self.width % BITS;
+        if used == 0 {
+            return;
+        }
+        let mask = (1 << used) - 1;
+        for row in self.words.chunks_exact_mut(self.stride) {
+            row[self.stride - 1] &= mask;
+        }
+    }
+
+    #[track_caller]
+    fn zip_with(&mut self, rhs: &Self, f: impl Fn(u64, u64) -> u64) {
+        if (self.width, self.height) != (rhs.width, rhs.height) {
+            panic!("grids should have the same dimensions");
+        }
+        for (a, &b) in self.words.iter_mut().zip(&rhs.words) {
+            *a = f(*a, b);
+        }
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+        for (p, &cell) in grid.iter() {
+            if cell {
+                bits.set(p, true);
+            }
+        }
+        bits
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $f:expr) => {
+        impl $op_assign<&BitGrid> for BitGrid {
+            /// Panics if the grids have different dimensions.
+            #[track_caller]
+            fn $method_assign(&mut self, rhs: &BitGrid) {
+                self.zip_with(rhs, $f);
+            }
+        }
+
+        impl $op<&BitGrid> for &BitGrid {
+            type Output = BitGrid;
+
+            /// Panics if the grids have different dimensions.
+            #[track_caller]
+            fn $method(self, rhs: &BitGrid) -> BitGrid {
+                let mut out = self.clone();
+                out.zip_with(rhs, $f);
+                out
+            }
+        }
+
+        impl $op<&BitGrid> for BitGrid {
+            type Output = BitGrid;
+
+            /// Panics if the grids have different dimensions.
+            #[track_caller]
+            fn $method(mut self, rhs: &BitGrid) -> BitGrid {
+                self.zip_with(rhs, $f);
+                self
+            }
+        }
+
+        impl $op<BitGrid> for BitGrid {
+            type Output = BitGrid;
+
+            /// Panics if the grids have different dimensions.
+            #[track_caller]
+            fn $method(self, rhs: BitGrid) -> BitGrid {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, |a, b| a ^ b);
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        !self.clone()
+    }
+}
+
+impl Not for BitGrid {
+    type Output = BitGrid;
+
+    fn not(mut self) -> BitGrid {
+        for word in &mut self.words {
+            *word = !*word;
+        }
+        self.mask_padding();
+        self
+    }
+}
+
+impl fmt::Display for BitGrid {
+    /// Writes `#` for set cells and `.` for unset cells.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..self.width {
+                f.write_str(if self.contains((x, y)) { "#" } else { "." })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The number of set neighbours of every cell of a [`BitGrid`], stored as 4 bit planes.
+///
+/// Returned by [`BitGrid::neighbour_counts4`] and [`BitGrid::neighbour_counts8`].
+#[derive(Clone, Debug)]
+pub struct NeighbourCounts {
+    planes: [BitGrid; 4],
+}
+
+impl NeighbourCounts {
+    fn new(width: usize, height: usize) -> Self {
+        let empty = BitGrid::new(width, height);
+        Self {
+            planes: [(); 4].map(|_| empty.clone()),
+        }
+    }
+
+    /// Adds one to the count of every set cell of `grid`, with a word-parallel ripple-carry adder.
+    fn add(&mut self, grid: &BitGrid) {
+        let [p0, p1, p2, p3] = &mut self.planes;
+        for (i, &bit) in grid.words.iter().enumerate() {
+            let carry0 = p0.words[i] & bit;
+            p0.words[i] ^= bit;
+            let carry1 = p1.words[i] & carry0;
+            p1.words[i] ^= carry0;
+            let carry2 = p2.words[i] & carry1;
+            p2.words[i] ^= carry1;
+            p3.words[i] ^= carry2;
+        }
+    }
+
+    /// Returns a grid with the cells set whose count is exactly `n`.
+    pub fn equal_to(&self, n: u8) -> BitGrid {
+        let mut out = !&BitGrid::new(self.planes[0].width, self.planes[0].height);
+        for (i, plane) in self.planes.iter().enumerate() {
+            if n >> i & 1 == 1 {
+                out &= plane;
+            } else {
+                out &= &!plane;
+            }
+        }
+        if n >= 16 {
+            out.clear();
+        }
+        out
+    }
+
+    /// Returns a grid with the cells set whose count is at least `n`.
+    pub fn at_least(&self, n: u8) -> BitGrid {
+        let mut out = BitGrid::new(self.planes[0].width, self.planes[0].height);
+        for k in n..16 {
+            out |= &self.equal_to(k);
+        }
+        out
+    }
+
+    /// Returns the count for the cell at `index`.
+    ///
+    /// Panics if `index` is out of bounds.
+    #[track_caller]
+    pub fn get(&self, index: impl GridIndex) -> u8 {
+        self.planes
+            .iter()
+            .enumerate()
+            .map(|(i, plane)| u8::from(plane.contains(index)) << i)
+            .sum()
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::VecDeque;
+
+use super::Grid;
+use crate::{Dir4, Point};
+
+/// Which cells count as adjacent when exploring a grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Only orthogonally adjacent cells.
+    Four,
+    /// Orthogonally and diagonally adjacent cells.
+    Eight,
+}
+
+/// A connected region of cells in a [`Grid`].
+///
+/// The perimeter and number of sides are measured along cell edges,
+/// counting both the outer boundary and the boundaries of any holes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    cells: Vec<Point>,
+    perimeter: usize,
+    sides: usize,
+}
+
+impl Region {
+    fn new(cells: Vec<Point>, contains: impl Fn(Point) -> bool) -> Self {
+        let mut perimeter = 0;
+        let mut sides = 0;
+        for &cell in &cells {
+            for dir in Dir4::ALL {
+                let a = contains(cell + dir);
+                let b = contains(cell + dir.turn_right());
+                perimeter += usize::from(!a);
+                // Each side of a rectilinear shape ends in exactly one corner, so count corners instead.
+                let diagonal = contains(cell + dir + dir.turn_right());
+                sides += usize::from((!a && !b) || (a && b && !diagonal));
+            }
+        }
+        Self {
+            cells,
+            perimeter,
+            sides,
+        }
+    }
+
+    /// Returns the cells in the region, in the order they were reached.
+    #[inline(always)]
+    pub fn cells(&self) -> &[Point] {
+        &self.cells
+    }
+
+    /// Returns the number of cells in the region.
+    #[inline(always)]
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns the number of cell edges between the region and cells outside it.
+    #[inline(always)]
+    pub fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    /// Returns the number of straight sides of the region's boundary.
+    #[inline(always)]
+    pub fn sides(&self) -> usize {
+        self.sides
+    }
+}
+
+/// The connected components of a [`Grid`], as returned by [`Grid::regions`].
+#[derive(Clone, Debug)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Returns a grid of the same size as the original, containing the label of the region each cell belongs to.
+    ///
+    /// Labels are indices into [`Regions::as_slice`], assigned in row-major order of each region's first cell.
+    #[inline(always)]
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    /// Returns the region containing `point`, or `None` if it is outside the grid.
+    #[inline]
+    pub fn region_of(&self, point: Point) -> Option<&Region> {
+        self.labels.get(point).map(|&label| &self.regions[label])
+    }
+
+    /// Returns the regions as a slice, indexed by label.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[Region] {
+        &self.regions
+    }
+
+    /// Returns an iterator over the regions, in order of label.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, Region> {
+        self.regions.iter()
+    }
+
+    /// Returns the number of regions.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// Returns whether there are no regions, which only happens for an empty grid.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a Regions {
+    type Item = &'a Region;
+    type IntoIter = std::slice::Iter<'a, Region>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the region containing `start`, where two adjacent cells are in the same region
+    /// if `connected` returns `true` for their values.
+    ///
+    /// Panics if `start` is outside the grid.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{Connectivity, Grid, Point};
+    ///
+    /// let grid = Grid::from_input(["AAB", "ABB", "AAB"]);
+    /// let region = grid.flood_fill(Point::new(0, 0), Connectivity::Four, |a, b| a == b);
+    ///
+    /// assert_eq!(region.area(), 5);
+    /// assert_eq!(region.perimeter(), 12);
+    /// assert_eq!(region.sides(), 8);
+    /// ```
+    #[track_caller]
+    pub fn flood_fill(
+        &self,
+        start: Point,
+        connectivity: Connectivity,
+        mut connected: impl FnMut(&T, &T) -> bool,
+    ) -> Region {
+        if !self.in_bounds(start) {
+            panic!("`start` should be inside the grid");
+        }
+        let mut seen = Grid::new(self.width, self.height, false);
+        let cells = self.fill(start, connectivity, &mut connected, |p| {
+            !std::mem::replace(&mut seen[p], true)
+        });
+        Region::new(cells, |p| seen.get(p).copied().unwrap_or(false))
+    }
+
+    /// Partitions the grid into connected regions, where two adjacent cells are in the same region
+    /// if `connected` returns `true` for their values.
+    ///
+    /// Every cell belongs to exactly one region. To leave some cells out, such as walls,
+    /// return `false` from `connected` when either value is excluded, and skip the resulting single-cell regions.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{Connectivity, Grid};
+    ///
+    /// let grid = Grid::from_input(["AAAA", "BBCD", "BBCC", "EEEC"]);
+    /// let regions = grid.regions(Connectivity::Four, |a, b| a == b);
+    ///
+    /// assert_eq!(regions.len(), 5);
+    /// let price: usize = regions.iter().map(|r| r.area() * r.perimeter()).sum();
+    /// assert_eq!(price, 140);
+    /// let discount: usize = regions.iter().map(|r| r.area() * r.sides()).sum();
+    /// assert_eq!(discount, 80);
+    ///
+    /// let diagonal = Grid::from_input(["#.", ".#"]);
+    /// assert_eq!(diagonal.regions(Connectivity::Four, |a, b| a == b).len(), 4);
+    /// assert_eq!(diagonal.regions(Connectivity::Eight, |a, b| a == b).len(), 2);
+    /// ```
+    pub fn regions(
+        &self,
+        connectivity: Connectivity,
+        mut connected: impl FnMut(&T, &T) -> bool,
+    ) -> Regions {
+        const UNLABELLED: usize = usize::MAX;
+
+        let mut labels = Grid::new(self.width, self.height, UNLABELLED);
+        let mut cells = Vec::new();
+        for start in self.points() {
+            if labels[start] != UNLABELLED {
+                continue;
+            }
+            let label = cells.len();
+            cells.push(self.fill(start, connectivity, &mut connected, |p| {
+                labels[p] == UNLABELLED && {
+                    labels[p] = label;
+                    true
+                }
+            }));
+        }
+        let regions = cells
+            .into_iter()
+            .enumerate()
+            .map(|(label, cells)| Region::new(cells, |p| labels.get(p) == Some(&label)))
+            .collect();
+        Regions { labels, regions }
+    }
+
+    /// Breadth-first search from `start`, where `visit` marks a cell as seen and returns whether it was unseen.
+    fn fill(
+        &self,
+        start: Point,
+        connectivity: Connectivity,
+        connected: &mut impl FnMut(&T, &T) -> bool,
+        mut visit: impl FnMut(Point) -> bool,
+    ) -> Vec<Point> {
+        visit(start);
+        let mut cells = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(p) = queue.pop_front() {
+            cells.push(p);
+            let neighbours: &mut dyn Iterator<Item = Point> = match connectivity {
+                Connectivity::Four => &mut self.neighbours4(p),
+                Connectivity::Eight => &mut self.neighbours8(p),
+            };
+            for next in neighbours {
+                if connected(&self[p], &self[next]) && visit(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        cells
+    }
+}
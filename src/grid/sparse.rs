@@ -0,0 +1,347 @@
+use std::{
+    cell::Cell,
+    collections::{hash_map, HashMap},
+    fmt,
+    ops::Index,
+};
+
+use super::Grid;
+use crate::Point;
+
+/// An unbounded grid that only stores occupied cells, keyed by position.
+///
+/// Useful when a pattern grows beyond the bounds of the input, or when most cells are empty.
+/// The bounding box of the occupied cells is tracked automatically, and is recomputed lazily after removals.
+///
+/// # Examples
+/// ```
+/// use aoc::{Point, SparseGrid};
+///
+/// let mut elves = SparseGrid::from_input_map(["#.", ".#"], |b| (b == b'#').then_some(()));
+///
+/// assert_eq!(elves.len(), 2);
+/// assert_eq!(elves.bounds(), Some((Point::new(0, 0), Point::new(1, 1))));
+///
+/// elves.remove(Point::new(0, 0));
+/// elves.insert(Point::new(-2, 1), ());
+///
+/// assert_eq!(elves.bounds(), Some((Point::new(-2, 1), Point::new(1, 1))));
+/// assert_eq!(elves.render(|cell| if cell.is_some() { '#' } else { '.' }), "#..#");
+/// ```
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Cell<Option<(Point, Point)>>,
+    stale: Cell<bool>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty `SparseGrid`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: Cell::new(None),
+            stale: Cell::new(false),
+        }
+    }
+
+    /// Creates a sparse grid from the lines of an `Input`, or any other iterator of lines,
+    /// mapping each byte to a cell with `f`. Bytes mapped to `None` are left empty.
+    pub fn from_input_map<I, S>(input: I, mut f: impl FnMut(u8) -> Option<T>) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut grid = Self::new();
+        for (y, line) in input.into_iter().enumerate() {
+            for (x, &b) in line.as_ref().as_bytes().iter().enumerate() {
+                if let Some(value) = f(b) {
+                    grid.insert(Point::new(x as i64, y as i64), value);
+                }
+            }
+        }
+        grid
+    }
+
+    /// Creates a sparse grid from the cells of a dense `Grid` for which `keep` returns `true`.
+    pub fn from_grid(grid: &Grid<T>, mut keep: impl FnMut(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        grid.iter()
+            .filter(|&(_, cell)| keep(cell))
+            .map(|(p, cell)| (p, cell.clone()))
+            .collect()
+    }
+
+    /// Returns the number of occupied cells.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns whether there are no occupied cells.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns whether the cell at `point` is occupied.
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Returns a reference to the cell at `point`, or `None` if it is empty.
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    /// Returns a mutable reference to the cell at `point`, or `None` if it is empty.
+    #[inline]
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Sets the cell at `point` to `value`, returning the previous value if it was occupied.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        if !self.stale.get() {
+            let bounds = match self.bounds.get() {
+                Some((min, max)) => (
+                    Point::new(min.x.min(point.x), min.y.min(point.y)),
+                    Point::new(max.x.max(point.x), max.y.max(point.y)),
+                ),
+                None => (point, point),
+            };
+            self.bounds.set(Some(bounds));
+        }
+        self.cells.insert(point, value)
+    }
+
+    /// Empties the cell at `point`, returning its value if it was occupied.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+        if let Some((min, max)) = self.bounds.get() {
+            if point.x == min.x || point.y == min.y || point.x == max.x || point.y == max.y {
+                self.stale.set(true);
+            }
+        }
+        Some(value)
+    }
+
+    /// Empties every cell.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds.set(None);
+        self.stale.set(false);
+    }
+
+    /// Returns the minimum and maximum corners of the smallest rectangle containing every occupied cell,
+    /// or `None` if there are no occupied cells.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        if self.stale.replace(false) {
+            let mut points = self.cells.keys();
+            let bounds = points.next().map(|&first| {
+                points.fold((first, first), |(min, max), p| {
+                    (
+                        Point::new(min.x.min(p.x), min.y.min(p.y)),
+                        Point::new(max.x.max(p.x), max.y.max(p.y)),
+                    )
+                })
+            });
+            self.bounds.set(bounds);
+        }
+        self.bounds.get()
+    }
+
+    /// Returns an iterator over the occupied positions, in arbitrary order.
+    #[inline]
+    pub fn points(&self) -> hash_map::Keys<'_, Point, T> {
+        self.cells.keys()
+    }
+
+    /// Returns an iterator over the occupied positions and their values, in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> hash_map::Iter<'_, Point, T> {
+        self.cells.iter()
+    }
+
+    /// Renders the bounding box as text, with one line per row, mapping each cell to a character with `f`.
+    ///
+    /// Returns an empty string if there are no occupied cells.
+    pub fn render(&self, mut f: impl FnMut(Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+        let mut s = String::new();
+        for y in min.y..=max.y {
+            if y > min.y {
+                s.push('\n');
+            }
+            for x in min.x..=max.x {
+                s.push(f(self.get(Point::new(x, y))));
+            }
+        }
+        s
+    }
+
+    /// Copies the bounding box into a dense `Grid`, along with the position of its top-left corner.
+    ///
+    /// Returns `None` if there are no occupied cells.
+    pub fn to_grid(&self) -> Option<(Grid<Option<T>>, Point)>
+    where
+        T: Clone,
+    {
+        let (min, max) = self.bounds()?;
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+        let grid = Grid::from_fn(width, height, |p| self.get(p + min).cloned());
+        Some((grid, min))
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for SparseGrid<T> {}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SparseGrid<T> {
+    type Item = (&'a Point, &'a T);
+    type IntoIter = hash_map::Iter<'a, Point, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: Point) -> &Self::Output {
+        self.get(index)
+            .expect("sparse grid cell should be occupied")
+    }
+}
+
+impl fmt::Display for SparseGrid<u8> {
+    /// Renders the bounding box, with `.` for empty cells.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|cell| cell.map_or('.', |&b| char::from(b))))
+    }
+}
+
+impl fmt::Display for SparseGrid<char> {
+    /// Renders the bounding box, with `.` for empty cells.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|cell| cell.copied().unwrap_or('.')))
+    }
+}
+
+/// A view of a [`Grid`] repeated infinitely in every direction, as returned by [`Grid::tiled`].
+///
+/// # Examples
+/// ```
+/// use aoc::{Grid, Point};
+///
+/// let grid = Grid::from_input(["ab", "cd"]);
+/// let tiled = grid.tiled();
+///
+/// assert_eq!(tiled[Point::new(2, 0)], b'a');
+/// assert_eq!(tiled[Point::new(-1, -1)], b'd');
+/// assert_eq!(tiled.tile_of(Point::new(-1, 5)), Point::new(-1, 2));
+/// ```
+#[derive(Debug)]
+pub struct Tiled<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> Clone for Tiled<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for Tiled<'a, T> {}
+
+impl<T> Grid<T> {
+    /// Returns a view of the grid repeated infinitely in every direction,
+    /// so that any point can be indexed by wrapping it into the grid.
+    #[inline]
+    pub fn tiled(&self) -> Tiled<'_, T> {
+        Tiled { grid: self }
+    }
+}
+
+impl<'a, T> Tiled<'a, T> {
+    /// Returns the position in the original grid that `point` wraps to.
+    ///
+    /// Panics if the grid is empty.
+    #[inline]
+    #[track_caller]
+    pub fn wrap(&self, point: Point) -> Point {
+        Point::new(
+            point.x.rem_euclid(self.grid.width as i64),
+            point.y.rem_euclid(self.grid.height as i64),
+        )
+    }
+
+    /// Returns which copy of the grid `point` is in, where `(0, 0)` is the original.
+    ///
+    /// Panics if the grid is empty.
+    #[inline]
+    #[track_caller]
+    pub fn tile_of(&self, point: Point) -> Point {
+        Point::new(
+            point.x.div_euclid(self.grid.width as i64),
+            point.y.div_euclid(self.grid.height as i64),
+        )
+    }
+
+    /// Returns the underlying grid.
+    #[inline(always)]
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+}
+
+impl<'a, T> Index<Point> for Tiled<'a, T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: Point) -> &Self::Output {
+        &self.grid[self.wrap(index)]
+    }
+}
@@ -0,0 +1,386 @@
+use std::{fmt, ops::Index};
+
+use super::{write_rows, Grid, GridIndex};
+use crate::Point;
+
+/// One of the 8 symmetries of a rectangle: the rotations and reflections that map it onto itself.
+///
+/// # Examples
+/// ```
+/// use aoc::{Grid, Symmetry};
+///
+/// let grid = Grid::from_input(["ab", "cd", "ef"]);
+///
+/// assert_eq!(grid.view().transform(Symmetry::RotateRight).to_string(), "eca\nfdb");
+/// assert_eq!(grid.view().transform(Symmetry::FlipHorizontal).to_string(), "ba\ndc\nfe");
+/// assert_eq!(grid.view().transform(Symmetry::Transpose).to_string(), "ace\nbdf");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    /// Leaves the grid unchanged.
+    Identity,
+    /// Rotates the grid 90 degrees clockwise.
+    RotateRight,
+    /// Rotates the grid 180 degrees.
+    Rotate180,
+    /// Rotates the grid 90 degrees anticlockwise.
+    RotateLeft,
+    /// Mirrors the grid left to right.
+    FlipHorizontal,
+    /// Mirrors the grid top to bottom.
+    FlipVertical,
+    /// Reflects the grid in its main diagonal, swapping rows and columns.
+    Transpose,
+    /// Reflects the grid in its anti-diagonal.
+    AntiTranspose,
+}
+
+impl Symmetry {
+    /// All 8 symmetries, starting with the 4 rotations.
+    pub const ALL: [Self; 8] = [
+        Self::Identity,
+        Self::RotateRight,
+        Self::Rotate180,
+        Self::RotateLeft,
+        Self::FlipHorizontal,
+        Self::FlipVertical,
+        Self::Transpose,
+        Self::AntiTranspose,
+    ];
+
+    /// Returns whether `self` swaps the width and height of a grid.
+    #[inline]
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Self::RotateRight | Self::RotateLeft | Self::Transpose | Self::AntiTranspose
+        )
+    }
+
+    /// Maps a position in the transformed grid to the position it came from in a `w` by `h` grid.
+    fn source(self, x: i64, y: i64, w: i64, h: i64) -> (i64, i64) {
+        match self {
+            Self::Identity => (x, y),
+            Self::RotateRight => (y, h - 1 - x),
+            Self::Rotate180 => (w - 1 - x, h - 1 - y),
+            Self::RotateLeft => (w - 1 - y, x),
+            Self::FlipHorizontal => (w - 1 - x, y),
+            Self::FlipVertical => (x, h - 1 - y),
+            Self::Transpose => (y, x),
+            Self::AntiTranspose => (w - 1 - y, h - 1 - x),
+        }
+    }
+}
+
+/// A borrowed, possibly rotated or reflected, rectangular region of a [`Grid`].
+///
+/// Views never copy cells: indexing into a view remaps the index into the underlying grid.
+/// Use [`GridView::to_grid`] to copy the cells into a new `Grid`.
+///
+/// # Examples
+/// ```
+/// use aoc::{Grid, Symmetry};
+///
+/// let grid = Grid::from_input(["abc", "def", "ghi"]);
+/// let sub = grid.sub_grid(1, 1, 2, 2);
+///
+/// assert_eq!(sub.to_string(), "ef\nhi");
+/// assert_eq!(sub.transform(Symmetry::RotateLeft).to_string(), "fi\neh");
+/// assert_eq!(sub[(0, 1)], b'h');
+/// ```
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    width: usize,
+    height: usize,
+    origin: (i64, i64),
+    x_step: (i64, i64),
+    y_step: (i64, i64),
+}
+
+impl<'a, T> Clone for GridView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for GridView<'a, T> {}
+
+impl<T> Grid<T> {
+    /// Returns a view of the whole grid.
+    #[inline]
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            width: self.width,
+            height: self.height,
+            origin: (0, 0),
+            x_step: (1, 0),
+            y_step: (0, 1),
+        }
+    }
+
+    /// Returns a view of the `width` by `height` region of the grid with top-left corner `(x, y)`.
+    ///
+    /// Panics if the region is not inside the grid.
+    #[inline]
+    #[track_caller]
+    pub fn sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> GridView<'_, T> {
+        self.view().sub_grid(x, y, width, height)
+    }
+
+    /// Returns an iterator over views of all 8 symmetries of the grid, in the order of [`Symmetry::ALL`].
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::Grid;
+    ///
+    /// let grid = Grid::from_input(["#.", ".."]);
+    /// let corners: Vec<_> = grid.symmetries().map(|v| v.position(&b'#').unwrap()).collect();
+    ///
+    /// assert_eq!(corners.len(), 8);
+    /// assert!(corners.iter().all(|p| p.x == 0 || p.x == 1));
+    /// ```
+    pub fn symmetries(&self) -> impl Iterator<Item = GridView<'_, T>> {
+        let view = self.view();
+        Symmetry::ALL.into_iter().map(move |s| view.transform(s))
+    }
+
+    /// Returns a copy of the grid transformed by `symmetry`.
+    #[inline]
+    pub fn transformed(&self, symmetry: Symmetry) -> Self
+    where
+        T: Clone,
+    {
+        self.view().transform(symmetry).to_grid()
+    }
+
+    /// Returns a copy of the grid rotated 90 degrees clockwise.
+    #[inline]
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Symmetry::RotateRight)
+    }
+
+    /// Returns a copy of the grid rotated 180 degrees.
+    #[inline]
+    pub fn rotate_180(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Symmetry::Rotate180)
+    }
+
+    /// Returns a copy of the grid rotated 90 degrees anticlockwise.
+    #[inline]
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Symmetry::RotateLeft)
+    }
+
+    /// Returns a copy of the grid mirrored left to right.
+    #[inline]
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Symmetry::FlipHorizontal)
+    }
+
+    /// Returns a copy of the grid mirrored top to bottom.
+    #[inline]
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Symmetry::FlipVertical)
+    }
+
+    /// Returns a copy of the grid with rows and columns swapped.
+    #[inline]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Symmetry::Transpose)
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// Returns the number of columns in the view.
+    #[inline(always)]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows in the view.
+    #[inline(always)]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns whether `index` is inside the view.
+    #[inline]
+    pub fn in_bounds(&self, index: impl GridIndex) -> bool {
+        index
+            .to_xy()
+            .is_some_and(|(x, y)| x < self.width && y < self.height)
+    }
+
+    /// Returns a reference to the cell at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: impl GridIndex) -> Option<&'a T> {
+        let (x, y) = index.to_xy()?;
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let (x, y) = self.source(x as i64, y as i64);
+        Some(&self.grid.cells[y as usize * self.grid.width + x as usize])
+    }
+
+    /// Returns the position in the underlying grid of the cell at `index` in the view,
+    /// or `None` if it is out of bounds.
+    #[inline]
+    pub fn grid_position(&self, index: impl GridIndex) -> Option<Point> {
+        let (x, y) = index.to_xy()?;
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let (x, y) = self.source(x as i64, y as i64);
+        Some(Point::new(x, y))
+    }
+
+    /// Returns the view transformed by `symmetry`.
+    pub fn transform(&self, symmetry: Symmetry) -> Self {
+        let (w, h) = (self.width as i64, self.height as i64);
+        let origin = symmetry.source(0, 0, w, h);
+        let x_step = symmetry.source(1, 0, w, h);
+        let y_step = symmetry.source(0, 1, w, h);
+        let (width, height) = if symmetry.swaps_axes() {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        };
+        Self {
+            grid: self.grid,
+            width,
+            height,
+            origin: self.source(origin.0, origin.1),
+            x_step: self.step(x_step.0 - origin.0, x_step.1 - origin.1),
+            y_step: self.step(y_step.0 - origin.0, y_step.1 - origin.1),
+        }
+    }
+
+    /// Returns a view of the `width` by `height` region of the view with top-left corner `(x, y)`.
+    ///
+    /// Panics if the region is not inside the view.
+    #[track_caller]
+    pub fn sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Self {
+        if x + width > self.width || y + height > self.height {
+            panic!("sub-grid should be inside the grid");
+        }
+        Self {
+            width,
+            height,
+            origin: self.source(x as i64, y as i64),
+            ..*self
+        }
+    }
+
+    /// Returns an iterator over the cells in row `y` of the view, from left to right.
+    ///
+    /// Panics if `y` is at least the height of the view.
+    #[track_caller]
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &'a T> {
+        if y >= self.height {
+            panic!("row index out of bounds");
+        }
+        let view = *self;
+        (0..self.width).map(move |x| view.get((x, y)).unwrap())
+    }
+
+    /// Returns an iterator over the rows of the view, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y))
+    }
+
+    /// Returns an iterator over the positions of all cells in the view in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height as i64).flat_map(move |y| (0..width as i64).map(move |x| Point::new(x, y)))
+    }
+
+    /// Returns an iterator over the positions and values of all cells in the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'a T)> {
+        let view = *self;
+        self.points().map(move |p| (p, view.get(p).unwrap()))
+    }
+
+    /// Returns the position of the first cell in row-major order equal to `value`.
+    pub fn position(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|&(_, cell)| cell == value).map(|(p, _)| p)
+    }
+
+    /// Copies the cells of the view into a new `Grid`.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |p| self[p].clone())
+    }
+
+    #[inline]
+    fn source(&self, x: i64, y: i64) -> (i64, i64) {
+        let (dx, dy) = self.step(x, y);
+        (self.origin.0 + dx, self.origin.1 + dy)
+    }
+
+    #[inline]
+    fn step(&self, x: i64, y: i64) -> (i64, i64) {
+        (
+            self.x_step.0 * x + self.y_step.0 * y,
+            self.x_step.1 * x + self.y_step.1 * y,
+        )
+    }
+}
+
+impl<'a, T, I: GridIndex> Index<I> for GridView<'a, T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: I) -> &Self::Output {
+        self.get(index).expect("grid index out of bounds")
+    }
+}
+
+impl<'a, 'b, T: PartialEq> PartialEq<GridView<'b, T>> for GridView<'a, T> {
+    /// Returns whether the views have the same dimensions and the same cells, regardless of their underlying grids.
+    fn eq(&self, other: &GridView<'b, T>) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.points().all(|p| self[p] == other[p])
+    }
+}
+
+impl<'a, T: Eq> Eq for GridView<'a, T> {}
+
+impl<'a> fmt::Display for GridView<'a, u8> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(self.rows(), f, |&b| char::from(b))
+    }
+}
+
+impl<'a> fmt::Display for GridView<'a, char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(self.rows(), f, |&ch| ch)
+    }
+}
@@ -0,0 +1,303 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The axial offsets of the 6 neighbours of a hex, anticlockwise starting from `+q`.
+const AXIAL: [Hex; 6] = [
+    Hex::new(1, 0),
+    Hex::new(1, -1),
+    Hex::new(0, -1),
+    Hex::new(-1, 0),
+    Hex::new(-1, 1),
+    Hex::new(0, 1),
+];
+
+/// A position on a hexagonal grid, in axial coordinates.
+///
+/// The third cube coordinate is `s = -q - r`. Coordinates are the same for pointy-topped and flat-topped grids;
+/// only the names of the directions differ, as [`PointyDir`] and [`FlatDir`].
+///
+/// # Examples
+/// ```
+/// use aoc::{FlatDir, Hex};
+///
+/// // Hex Ed: `ne,ne,s,s` ends up 2 steps away.
+/// let end = FlatDir::parse_seq("ne,ne,s,s").fold(Hex::ZERO, |hex, dir| hex + dir);
+///
+/// assert_eq!(end, Hex::new(2, 0));
+/// assert_eq!(end.distance(Hex::ZERO), 2);
+/// assert_eq!(Hex::ZERO.ring(2).count(), 12);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    /// The origin.
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// Creates a new `Hex` from axial coordinates.
+    #[inline]
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// Creates a new `Hex` from cube coordinates.
+    ///
+    /// Panics if `q + r + s` is not zero.
+    #[inline]
+    #[track_caller]
+    pub fn from_cube(q: i64, r: i64, s: i64) -> Self {
+        if q + r + s != 0 {
+            panic!("cube coordinates should sum to zero");
+        }
+        Self { q, r }
+    }
+
+    /// Returns the third cube coordinate, `-q - r`.
+    #[inline]
+    pub fn s(self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Returns the number of steps between `self` and `other`.
+    #[inline]
+    pub fn distance(self, other: Self) -> i64 {
+        let d = self - other;
+        (d.q.abs() + d.r.abs() + d.s().abs()) / 2
+    }
+
+    /// Returns the 6 adjacent hexes.
+    #[inline]
+    pub fn neighbours(self) -> [Self; 6] {
+        AXIAL.map(|offset| self + offset)
+    }
+
+    /// Returns an iterator over the hexes exactly `radius` steps from `self`, going around the ring.
+    ///
+    /// A radius of `0` yields only `self`.
+    pub fn ring(self, radius: u32) -> impl Iterator<Item = Self> {
+        let radius = radius as i64;
+        let mut hex = self + AXIAL[4] * radius;
+        let sides = if radius == 0 { 1 } else { 6 };
+        (0..sides)
+            .flat_map(move |side| (0..radius.max(1)).map(move |_| side))
+            .map(move |side| {
+                let current = hex;
+                hex += AXIAL[side];
+                current
+            })
+    }
+
+    /// Returns an iterator over the hexes at most `radius` steps from `self`, ring by ring outwards.
+    pub fn spiral(self, radius: u32) -> impl Iterator<Item = Self> {
+        (0..=radius).flat_map(move |r| self.ring(r))
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl AddAssign for Hex {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Hex {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Splits a sequence of compass directions into tokens, with or without separators.
+///
+/// A `n` or `s` followed by `e` or `w` is always taken as a single two-letter token.
+fn tokens(s: &str) -> impl Iterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !bytes[i].is_ascii_alphabetic() {
+            i += 1;
+        }
+        if i >= bytes.len() {
+            return None;
+        }
+        let start = i;
+        let two = matches!(bytes[i].to_ascii_lowercase(), b'n' | b's')
+            && bytes
+                .get(i + 1)
+                .is_some_and(|b| matches!(b.to_ascii_lowercase(), b'e' | b'w'));
+        i += if two { 2 } else { 1 };
+        Some(&s[start..i])
+    })
+}
+
+macro_rules! hex_dir {
+    (
+        $(#[$attr:meta])*
+        $name:ident { $($variant:ident = $token:literal => $index:literal),* $(,)? }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $name {
+            $($variant),*
+        }
+
+        impl $name {
+            /// All 6 directions, anticlockwise.
+            pub const ALL: [Self; 6] = [$(Self::$variant),*];
+
+            /// Returns the direction named by `token`, ignoring case, or `None` if there isn't one.
+            pub fn try_from_token(token: &str) -> Option<Self> {
+                $(
+                    if token.eq_ignore_ascii_case($token) {
+                        return Some(Self::$variant);
+                    }
+                )*
+                None
+            }
+
+            /// Returns the direction named by `token`, ignoring case.
+            ///
+            /// Panics if `token` does not name a direction.
+            #[inline]
+            #[track_caller]
+            pub fn from_token(token: &str) -> Self {
+                Self::try_from_token(token).expect("token should name a direction")
+            }
+
+            /// Returns an iterator over the directions in a sequence such as `"ne,ne,s"` or `"esenee"`.
+            ///
+            /// Any characters other than letters are ignored. The returned iterator panics on an invalid direction.
+            pub fn parse_seq(s: &str) -> impl Iterator<Item = Self> + '_ {
+                tokens(s).map(Self::from_token)
+            }
+
+            /// Returns the index of `self` in `ALL`.
+            #[inline(always)]
+            pub fn index(self) -> usize {
+                self as usize
+            }
+
+            /// Returns the offset of one step in this direction.
+            #[inline]
+            pub fn offset(self) -> Hex {
+                const OFFSETS: [Hex; 6] = [$(AXIAL[$index]),*];
+                OFFSETS[self.index()]
+            }
+
+            /// Returns the direction 60 degrees clockwise from `self`.
+            #[inline]
+            pub fn turn_right(self) -> Self {
+                Self::ALL[(self.index() + 5) % 6]
+            }
+
+            /// Returns the direction 60 degrees anticlockwise from `self`.
+            #[inline]
+            pub fn turn_left(self) -> Self {
+                Self::ALL[(self.index() + 1) % 6]
+            }
+
+            /// Returns the opposite direction to `self`.
+            #[inline]
+            pub fn reverse(self) -> Self {
+                Self::ALL[(self.index() + 3) % 6]
+            }
+        }
+
+        impl Add<$name> for Hex {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: $name) -> Self::Output {
+                self + rhs.offset()
+            }
+        }
+
+        impl AddAssign<$name> for Hex {
+            #[inline]
+            fn add_assign(&mut self, rhs: $name) {
+                *self = *self + rhs;
+            }
+        }
+    };
+}
+
+hex_dir! {
+    /// A direction on a pointy-topped hex grid, where neighbours are to the east and west but not north and south.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{Hex, PointyDir};
+    ///
+    /// // Lobby Layout: `nwwswee` flips the reference tile itself.
+    /// let tile = PointyDir::parse_seq("nwwswee").fold(Hex::ZERO, |hex, dir| hex + dir);
+    ///
+    /// assert_eq!(tile, Hex::ZERO);
+    /// assert_eq!(PointyDir::E.turn_left(), PointyDir::NE);
+    /// ```
+    PointyDir {
+        E = "e" => 0,
+        NE = "ne" => 1,
+        NW = "nw" => 2,
+        W = "w" => 3,
+        SW = "sw" => 4,
+        SE = "se" => 5,
+    }
+}
+
+hex_dir! {
+    /// A direction on a flat-topped hex grid, where neighbours are to the north and south but not east and west.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{FlatDir, Hex};
+    ///
+    /// assert_eq!(FlatDir::from_token("NE").offset(), Hex::new(1, -1));
+    /// assert_eq!(FlatDir::N.reverse(), FlatDir::S);
+    /// ```
+    FlatDir {
+        NE = "ne" => 1,
+        N = "n" => 2,
+        NW = "nw" => 3,
+        SW = "sw" => 4,
+        S = "s" => 5,
+        SE = "se" => 0,
+    }
+}
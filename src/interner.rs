@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+/// The number of keys for names of up to 3 lowercase letters, with each letter as a digit from 1 to 26 in base 27.
+const SHORT_KEYS: usize = 27 * 27 * 27;
+
+/// Marks an unused slot in the table of short names.
+const VACANT: u32 = u32::MAX;
+
+/// Gives each distinct name a compact `u32` id, counting up from 0, and turns ids back into names.
+///
+/// Names of 1 to 3 lowercase ASCII letters, the most common kind, are looked up in a flat table rather than hashed.
+///
+/// # Examples
+/// ```
+/// use aoc::{Interner, Parse};
+///
+/// // Monkey Math: each monkey is named, and refers to other monkeys by name.
+/// let mut names = Interner::new();
+/// let mut parser = "root: pppw + sjmn".as_parser();
+/// let job = [parser.intern(&mut names), parser.intern(&mut names), parser.intern(&mut names)];
+///
+/// assert_eq!(job, [0, 1, 2]);
+/// assert_eq!(parser.try_intern(&mut names), None);
+/// assert_eq!(names.intern("sjmn"), 2);
+/// assert_eq!(names.get("humn"), None);
+/// assert_eq!(names.name(1), "pppw");
+///
+/// assert_eq!(names.intern("broadcaster"), 3);
+/// assert_eq!(names.get("broadcaster"), Some(3));
+/// assert_eq!(names.intern("jx"), 4);
+/// assert_eq!(names.get("jx"), Some(4));
+/// assert_eq!(names.len(), 5);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Interner<'a> {
+    names: Vec<&'a str>,
+    ids: HashMap<&'a str, u32>,
+    short: Vec<u32>,
+}
+
+impl<'a> Interner<'a> {
+    /// Creates an empty `Interner`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id of `name`, giving it the next id if it hasn't been seen before.
+    ///
+    /// Panics if there are already `u32::MAX` names.
+    #[track_caller]
+    pub fn intern(&mut self, name: &'a str) -> u32 {
+        if let Some(key) = short_key(name) {
+            if self.short.is_empty() {
+                self.short = vec![VACANT; SHORT_KEYS];
+            }
+            if self.short[key] == VACANT {
+                self.short[key] = self.push(name);
+            }
+            self.short[key]
+        } else {
+            match self.ids.get(name) {
+                Some(&id) => id,
+                None => {
+                    let id = self.push(name);
+                    self.ids.insert(name, id);
+                    id
+                }
+            }
+        }
+    }
+
+    /// Returns the id of `name`, or `None` if it hasn't been interned.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<u32> {
+        match short_key(name) {
+            Some(key) => self.short.get(key).copied().filter(|&id| id != VACANT),
+            None => self.ids.get(name).copied(),
+        }
+    }
+
+    /// Returns the name with the given id.
+    ///
+    /// Panics if no name has that id.
+    #[inline]
+    #[track_caller]
+    pub fn name(&self, id: u32) -> &'a str {
+        self.names[id as usize]
+    }
+
+    /// Returns the interned names, indexed by id.
+    #[inline(always)]
+    pub fn names(&self) -> &[&'a str] {
+        &self.names
+    }
+
+    /// Returns the number of distinct names interned.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns whether no names have been interned.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    #[track_caller]
+    fn push(&mut self, name: &'a str) -> u32 {
+        let id = self.names.len() as u32;
+        if id == VACANT {
+            panic!("there should be fewer than `u32::MAX` names");
+        }
+        self.names.push(name);
+        id
+    }
+}
+
+/// Returns the slot for `name` in the table of short names, if it is 1 to 3 lowercase ASCII letters.
+#[inline]
+fn short_key(name: &str) -> Option<usize> {
+    let bytes = name.as_bytes();
+    if bytes.is_empty() || bytes.len() > 3 || !bytes.iter().all(u8::is_ascii_lowercase) {
+        return None;
+    }
+    Some(
+        bytes
+            .iter()
+            .fold(0, |key, &b| key * 27 + (b - b'a') as usize + 1),
+    )
+}
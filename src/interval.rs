@@ -0,0 +1,224 @@
+use std::ops::RangeInclusive;
+
+use crate::Integer;
+
+/// A set of integers, stored as sorted, disjoint, non-adjacent inclusive ranges.
+///
+/// Ranges are merged as they are inserted, so the stored ranges are always the maximal runs of the set.
+///
+/// # Examples
+/// ```
+/// use aoc::{IntervalSet, Parse};
+///
+/// // Cafeteria: fresh ingredient ID ranges, then some IDs to check.
+/// let fresh: IntervalSet<u64> = ["3-5", "10-14", "16-20", "12-18"]
+///     .iter()
+///     .map(|line| {
+///         let [start, end] = line.uints::<2, u64>();
+///         start..=end
+///     })
+///     .collect();
+///
+/// assert_eq!(fresh.iter().collect::<Vec<_>>(), [3..=5, 10..=20]);
+/// assert_eq!(fresh.len(), 14);
+/// assert_eq!([1, 5, 8, 11, 17, 32].iter().filter(|&&id| fresh.contains(id)).count(), 3);
+/// assert_eq!(fresh.gaps().collect::<Vec<_>>(), [6..=9]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T = i64> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    /// Creates an empty `IntervalSet`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Returns the number of integers in the set.
+    pub fn len(&self) -> T {
+        self.ranges.iter().fold(T::ZERO, |total, &(start, end)| {
+            total + (end - start) + T::ONE
+        })
+    }
+
+    /// Returns whether the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the number of maximal ranges in the set.
+    #[inline]
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns the smallest integer in the set, or `None` if it is empty.
+    #[inline]
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|&(start, _)| start)
+    }
+
+    /// Returns the largest integer in the set, or `None` if it is empty.
+    #[inline]
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|&(_, end)| end)
+    }
+
+    /// Returns whether `value` is in the set, using a binary search.
+    #[inline]
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(i).is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Returns the maximal range containing `value`, or `None` if `value` is not in the set.
+    #[inline]
+    pub fn range_of(&self, value: T) -> Option<RangeInclusive<T>> {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges
+            .get(i)
+            .filter(|&&(start, _)| start <= value)
+            .map(|&(start, end)| start..=end)
+    }
+
+    /// Adds every integer in `range` to the set.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::IntervalSet;
+    ///
+    /// let mut set = IntervalSet::new();
+    /// set.insert(-5_000_000_000_000_000_000..=-5_000_000_000_000_000_000);
+    /// set.insert(5_000_000_000_000_000_000..=i64::MAX);
+    /// set.insert(0..=4);
+    /// set.insert(5..=9);
+    ///
+    /// assert_eq!(set.range_count(), 3);
+    /// assert_eq!(set.range_of(7), Some(0..=9));
+    /// assert_eq!(set.max(), Some(i64::MAX));
+    /// ```
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Ranges that overlap or touch the new one are merged into it. Comparing without subtracting
+        // avoids overflow when ranges are far apart.
+        let i = self
+            .ranges
+            .partition_point(|&(_, e)| e < start && e + T::ONE < start);
+        let j = self
+            .ranges
+            .partition_point(|&(s, _)| s <= end || end < T::MAX && s <= end + T::ONE);
+        if i < j {
+            start = start.min(self.ranges[i].0);
+            end = end.max(self.ranges[j - 1].1);
+        }
+        self.ranges.splice(i..j, [(start, end)]);
+    }
+
+    /// Removes every integer in `range` from the set.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let i = self.ranges.partition_point(|&(_, e)| e < start);
+        let j = self.ranges.partition_point(|&(s, _)| s <= end);
+        if i == j {
+            return;
+        }
+        let (first, last) = (self.ranges[i], self.ranges[j - 1]);
+        let left = (first.0 < start).then(|| (first.0, start - T::ONE));
+        let right = (end < last.1).then(|| (end + T::ONE, last.1));
+        self.ranges.splice(i..j, left.into_iter().chain(right));
+    }
+
+    /// Returns the integers in `self`, `other`, or both.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        out.extend(other.iter());
+        out
+    }
+
+    /// Returns the integers in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a0, a1)), Some(&(b0, b1))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a0.max(b0), a1.min(b1));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a1 < b1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Returns the integers in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        for range in other.iter() {
+            out.remove(range);
+        }
+        out
+    }
+
+    /// Returns the integers in `bounds` that are not in the set.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::IntervalSet;
+    ///
+    /// let covered: IntervalSet = [-3..=5, 7..=25].into_iter().collect();
+    /// let missing = covered.complement_within(0..=20);
+    ///
+    /// assert_eq!(missing.iter().collect::<Vec<_>>(), [6..=6]);
+    /// assert!(covered.union(&missing).iter().eq([-3..=25]));
+    /// assert!(covered.intersection(&missing).is_empty());
+    /// assert_eq!(covered.difference(&[0..=10].into_iter().collect()).len(), 18);
+    /// ```
+    pub fn complement_within(&self, bounds: RangeInclusive<T>) -> Self {
+        let mut out = Self::new();
+        out.insert(bounds);
+        out.difference(self)
+    }
+
+    /// Returns an iterator over the maximal ranges in the set, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// Returns an iterator over the maximal ranges between the smallest and largest integers that are not in the set,
+    /// in increasing order.
+    ///
+    /// Use [`IntervalSet::complement_within`] to include gaps at either end of some bounds.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges
+            .windows(2)
+            .map(|w| w[0].1 + T::ONE..=w[1].0 - T::ONE)
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
@@ -24,13 +24,55 @@
 //! assert_eq!(line_2.ints::<3, i32>(), [-157, 4, 1000]);
 //! assert_eq!(parse_line(line_2), [-157, 4, 1000]);
 //! ```
+mod automaton;
+mod cuboid;
+mod dir;
+mod fingerprint;
+mod graph;
+mod grid;
+mod hex;
 mod input;
+mod interner;
+mod interval;
+mod manifest;
 mod parse;
+mod point;
+mod polygon;
+pub mod progress;
+mod queue;
+mod range_map;
+mod rotation;
+pub mod search;
+mod solution;
+mod vector;
 
 use std::{env, fs, path::Path, time::Instant};
 
+pub use automaton::{Automaton, Coord, Cycle, GridAutomaton, Neighbours, SparseAutomaton};
+pub use cuboid::{Cuboid, CuboidSet, Rect};
+pub use dir::{Dir4, Dir8};
+pub use graph::{
+    find_cycle, schedule, strongly_connected_components, topological_sort, topological_sort_by_key,
+    AdjacencyMatrix, CycleError, Graph, Schedule,
+};
+pub use grid::{
+    BitGrid, Connectivity, Grid, GridIndex, GridView, NeighbourCounts, Region, Regions, SparseGrid,
+    Symmetry, Tiled,
+};
+pub use hex::{FlatDir, Hex, PointyDir};
 pub use input::{Input, Lines};
+pub use interner::Interner;
+pub use interval::IntervalSet;
+use manifest::{Manifest, Status};
 pub use parse::{Ints, IterUnwrap, Parse, Parser, UInts};
+pub use point::Point;
+pub use polygon::{Polygon, PolygonBuilder};
+use progress::Reporter;
+pub use queue::{BucketQueue, PriorityQueue, RadixHeap};
+pub use range_map::RangeMap;
+pub use rotation::Rotation;
+pub use solution::Solution;
+pub use vector::{Integer, Vec2, Vec3, Vec4, Vector};
 
 type Part<T> = fn(Input) -> T;
 
@@ -42,6 +84,13 @@ type Part<T> = fn(Input) -> T;
 /// - `aoc::parts!(1);` if only part 1 is implemented (`fn part_1`)
 /// - `aoc::parts!(2);` if only part 2 is implemented (`fn part_2`)
 /// - `aoc::parts!(1, 2);` if both parts are implemented
+///
+/// To run a type implementing [`Solution`] instead, name the type followed by the implemented parts:
+///
+/// - `aoc::parts!(Day01;);` if neither part is implemented
+/// - `aoc::parts!(Day01; 1);` if only part 1 is implemented
+/// - `aoc::parts!(Day01; 2);` if only part 2 is implemented
+/// - `aoc::parts!(Day01; 1, 2);` if both parts are implemented
 #[macro_export]
 macro_rules! parts {
     () => {
@@ -64,6 +113,11 @@ macro_rules! parts {
             aoc::run(Some(part_1), Some(part_2));
         }
     };
+    ($solution:ty; $($part:literal),*) => {
+        fn main() {
+            aoc::run_solution::<$solution>(&[$($part),*]);
+        }
+    };
 }
 
 /// The function that `aoc::parts!` inserts into `fn main`.
@@ -71,13 +125,89 @@ macro_rules! parts {
 /// Runs one of the parts with one of the puzzle inputs, depending on the command line arguments passed.
 ///
 /// Writes the puzzle answer and timing to files in `/data/[input]/[part]/out` so that `aocli` can read them.
+///
+/// # Stale answers
+///
+/// Each run also writes a `checksum` file containing the hash of the trimmed input that produced the answer.
+/// Passing `check` as a third argument (`[input] [part] check`) does not run the part, and instead compares the
+/// stored checksum against the current input file. If they differ, an empty `stale` file is written to the `out`
+/// directory; otherwise any existing `stale` file is removed. A normal run removes the `stale` file once the new answer
+/// and checksum are written, so it is kept if the part panics.
+///
+/// The same comparison is available as [`is_stale`].
+///
+/// # Progress
+///
+/// While the part is running, progress reported through [`progress`] is written to a `progress` file in the `out`
+/// directory, which is removed once the part returns.
+///
+/// # `result.json`
+///
+/// As well as the plain `answer`, `time` and `unimplemented` files, a `result.json` file is written to the same
+/// directory on every run. Its schema is stable: fields are only ever added, and the `schema` number is bumped if a
+/// field is removed or changes meaning.
+///
+/// ```json
+/// {
+///   "schema": 1,
+///   "status": "solved",
+///   "answer": "1234",
+///   "time": {
+///     "read": 81250,
+///     "solve": 5340
+///   },
+///   "input": {
+///     "checksum": "af63bd4c8601b7df"
+///   },
+///   "aoclib": "0.2.1",
+///   "profile": "release"
+/// }
+/// ```
+///
+/// - `schema`: the schema version, currently `1`.
+/// - `status`: `"solved"` or `"unimplemented"`.
+/// - `answer`: the answer as a string, or `null` if the part is unimplemented.
+/// - `time.read`: nanoseconds spent reading and splitting the input file, or `null`.
+/// - `time.solve`: nanoseconds spent in the part function (the same value as the `time` file), or `null`.
+/// - `input.checksum`: the 64-bit FNV-1a hash of the trimmed input as 16 lowercase hex digits, or `null`.
+/// - `aoclib`: the version of this crate.
+/// - `profile`: `"debug"` or `"release"`, depending on whether debug assertions are enabled.
 pub fn run<T1, T2>(part_1: Option<Part<T1>>, part_2: Option<Part<T2>>)
+where
+    T1: ToString,
+    T2: ToString,
+{
+    drive(part_1, part_2);
+}
+
+/// The function that `aoc::parts!(Type; ...)` inserts into `fn main`.
+///
+/// Works the same as [`run`], but parses the input with [`Solution::parse`] and then calls
+/// [`Solution::part_1`] or [`Solution::part_2`]. `parts` lists the implemented parts, and any other part is
+/// reported to `aocli` as unimplemented.
+///
+/// Panics if `parts` contains anything other than `1` or `2`.
+pub fn run_solution<S: Solution>(parts: &[u8]) {
+    if parts.iter().any(|&part| part != 1 && part != 2) {
+        panic!("invalid part number");
+    }
+    drive(
+        parts
+            .contains(&1)
+            .then_some(|input: Input| S::parse(input).part_1(input)),
+        parts
+            .contains(&2)
+            .then_some(|input: Input| S::parse(input).part_2(input)),
+    );
+}
+
+fn drive<T1, T2>(part_1: Option<impl FnOnce(Input) -> T1>, part_2: Option<impl FnOnce(Input) -> T2>)
 where
     T1: ToString,
     T2: ToString,
 {
     let args: Vec<_> = env::args().collect();
-    if args.len() != 3 {
+    if args.len() != 3 && args.len() != 4 {
         panic!("incorrect number of arguments");
     }
     let data = args[1].as_str();
@@ -89,6 +219,11 @@ where
     if part != "1" && part != "2" {
         panic!("invalid part argument");
     }
+    let check = match args.get(3).map(String::as_str) {
+        None => false,
+        Some("check") => true,
+        Some(_) => panic!("invalid mode argument"),
+    };
     let out_path = data_path.join(part).join("out");
     if out_path.try_exists().unwrap() {
         if !out_path.is_dir() {
@@ -98,52 +233,99 @@ where
         fs::create_dir_all(&out_path).unwrap();
     }
     data_path.push("input");
+    let stale_path = out_path.join("stale");
+    if check {
+        if fingerprint::is_stale(&data_path, &out_path) {
+            fs::write(stale_path, "").unwrap();
+        } else {
+            remove_flag(&stale_path);
+        }
+        return;
+    }
     let unimplemented_path = out_path.join("unimplemented");
     if part == "1" {
         let Some(part_1) = part_1 else {
-            fs::write(unimplemented_path, "").unwrap();
+            unimplemented(&unimplemented_path, &out_path);
             return;
         };
-        implemented(&unimplemented_path);
+        remove_flag(&unimplemented_path);
         run_part(&data_path, &out_path, part_1);
     } else {
         let Some(part_2) = part_2 else {
-            fs::write(unimplemented_path, "").unwrap();
+            unimplemented(&unimplemented_path, &out_path);
             return;
         };
-        implemented(&unimplemented_path);
+        remove_flag(&unimplemented_path);
         run_part(&data_path, &out_path, part_2);
     }
 }
 
-fn run_part<T>(data_path: &Path, out_path: &Path, part_n: Part<T>)
+fn run_part<T>(data_path: &Path, out_path: &Path, part_n: impl FnOnce(Input) -> T)
 where
     T: ToString,
 {
     if !data_path.is_file() {
         panic!("no input file");
     }
+    let start = Instant::now();
     let input = fs::read_to_string(data_path).unwrap();
     let input = input.trim_end();
     if input.is_empty() {
         panic!("input file is empty");
     }
     let lines: Vec<_> = input.lines().collect();
+    let read_time = start.elapsed().as_nanos();
+    let checksum = fingerprint::checksum(input);
     let input = Input::new(input, &lines);
+    let reporter = Reporter::start(out_path.join("progress"));
     let start = Instant::now();
     let answer = part_n(input);
     let time = start.elapsed().as_nanos();
+    reporter.finish();
     let answer = answer.to_string();
+    let manifest = Manifest {
+        status: Status::Solved,
+        answer: Some(&answer),
+        read_time: Some(read_time),
+        solve_time: Some(time),
+        checksum: Some(checksum),
+    };
+    fs::write(out_path.join("result.json"), manifest.to_string()).unwrap();
     fs::write(out_path.join("answer"), answer).unwrap();
     fs::write(out_path.join("time"), time.to_string()).unwrap();
+    fs::write(out_path.join("checksum"), fingerprint::to_hex(checksum)).unwrap();
+    // Only now do the stored outputs match the current input.
+    remove_flag(&out_path.join("stale"));
+}
+
+fn unimplemented(unimplemented_path: &Path, out_path: &Path) {
+    fs::write(unimplemented_path, "").unwrap();
+    remove_flag(&out_path.join("checksum"));
+    remove_flag(&out_path.join("stale"));
+    fs::write(
+        out_path.join("result.json"),
+        Manifest::unimplemented().to_string(),
+    )
+    .unwrap();
+}
+
+/// Returns whether the stored answer for the given input and part was computed from a different input file.
+///
+/// `data` and `part` are the same as the arguments passed to a solution binary, so the input file is
+/// `data/[data]/input` and the outputs are in `data/[data]/[part]/out`, relative to the current directory.
+///
+/// Returns `false` if there is no stored answer, and `true` if there is a stored answer but the input file is missing.
+pub fn is_stale(data: &str, part: &str) -> bool {
+    let data_path = Path::new("data").join(data);
+    fingerprint::is_stale(&data_path.join("input"), &data_path.join(part).join("out"))
 }
 
-fn implemented(path: &Path) {
+fn remove_flag(path: &Path) {
     if path.try_exists().unwrap() {
         if path.is_file() {
             fs::remove_file(path).unwrap();
         } else {
-            panic!("unexpected unimplemented directory found");
+            panic!("unexpected {} directory found", path.display());
         }
     }
 }
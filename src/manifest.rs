@@ -0,0 +1,109 @@
+use std::fmt::{self, Write};
+
+use crate::fingerprint;
+
+/// The version of the `result.json` schema written by this version of the crate.
+///
+/// Bumped only when a field is removed or changes meaning. New fields may be added without a bump.
+pub(crate) const SCHEMA_VERSION: u32 = 1;
+
+/// The outcome of running a part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Status {
+    Solved,
+    Unimplemented,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Solved => "solved",
+            Self::Unimplemented => "unimplemented",
+        }
+    }
+}
+
+/// The contents of `out/result.json`.
+#[derive(Clone, Debug)]
+pub(crate) struct Manifest<'a> {
+    pub status: Status,
+    pub answer: Option<&'a str>,
+    pub read_time: Option<u128>,
+    pub solve_time: Option<u128>,
+    pub checksum: Option<u64>,
+}
+
+impl<'a> Manifest<'a> {
+    pub fn unimplemented() -> Self {
+        Self {
+            status: Status::Unimplemented,
+            answer: None,
+            read_time: None,
+            solve_time: None,
+            checksum: None,
+        }
+    }
+}
+
+impl<'a> fmt::Display for Manifest<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{{")?;
+        writeln!(f, "  \"schema\": {SCHEMA_VERSION},")?;
+        writeln!(f, "  \"status\": {},", Json(self.status.as_str()))?;
+        writeln!(f, "  \"answer\": {},", OrNull(self.answer.map(Json)))?;
+        writeln!(f, "  \"time\": {{")?;
+        writeln!(f, "    \"read\": {},", OrNull(self.read_time))?;
+        writeln!(f, "    \"solve\": {}", OrNull(self.solve_time))?;
+        writeln!(f, "  }},")?;
+        writeln!(
+            f,
+            "  \"input\": {{\n    \"checksum\": {}\n  }},",
+            OrNull(self.checksum.map(|c| Json(fingerprint::to_hex(c))))
+        )?;
+        writeln!(f, "  \"aoclib\": {},", Json(env!("CARGO_PKG_VERSION")))?;
+        writeln!(f, "  \"profile\": {}", Json(profile()))?;
+        write!(f, "}}")
+    }
+}
+
+/// Returns the name of the build profile the solution was compiled with.
+fn profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// Formats a value as JSON.
+struct Json<T>(T);
+
+impl<T: AsRef<str>> fmt::Display for Json<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for ch in self.0.as_ref().chars() {
+            match ch {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                ch if ch.is_control() => write!(f, "\\u{:04x}", ch as u32)?,
+                ch => f.write_char(ch)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+/// Formats an optional value, writing `null` for `None`.
+struct OrNull<T>(Option<T>);
+
+impl<T: fmt::Display> fmt::Display for OrNull<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(value) => value.fmt(f),
+            None => f.write_str("null"),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use std::{fmt, marker::PhantomData, str::FromStr};
 
+use crate::Interner;
+
 /// Provides methods on `&str` for parsing.
 ///
 /// Everything is expected to succeed, so many of this trait's methods will panic on failure.
@@ -8,13 +10,13 @@ use std::{fmt, marker::PhantomData, str::FromStr};
 pub trait Parse {
     fn parse_uw<T: FromStrUnwrap>(&self) -> T;
     fn idx(&self, index: usize) -> u8;
-    fn ints_iter<T: FromStrUnwrap>(&self) -> Ints<T>;
+    fn ints_iter<T: FromStrUnwrap>(&self) -> Ints<'_, T>;
     fn ints<const N: usize, T: FromStrUnwrap>(&self) -> [T; N];
-    fn uints_iter<T: FromStrUnwrap>(&self) -> UInts<T>;
+    fn uints_iter<T: FromStrUnwrap>(&self) -> UInts<'_, T>;
     fn uints<const N: usize, T: FromStrUnwrap>(&self) -> [T; N];
     fn try_between(&self, pre: &str, post: &str) -> Option<&str>;
     // fn try_between_many(&self, pre: &str, post: &[&str]) -> Option<&str>;
-    fn as_parser(&self) -> Parser;
+    fn as_parser(&self) -> Parser<'_>;
 }
 
 impl Parse for str {
@@ -78,7 +80,7 @@ impl Parse for str {
     /// assert_eq!(ints.next(), Some(45));
     /// assert_eq!(ints.next(), None);
     /// ```
-    fn ints_iter<T: FromStrUnwrap>(&self) -> Ints<T> {
+    fn ints_iter<T: FromStrUnwrap>(&self) -> Ints<'_, T> {
         Ints {
             s: self,
             _phantom: PhantomData,
@@ -131,7 +133,7 @@ impl Parse for str {
     /// assert_eq!(ints.next(), Some(45));
     /// assert_eq!(ints.next(), None);
     /// ```
-    fn uints_iter<T: FromStrUnwrap>(&self) -> UInts<T> {
+    fn uints_iter<T: FromStrUnwrap>(&self) -> UInts<'_, T> {
         UInts {
             s: self,
             _phantom: PhantomData,
@@ -260,7 +262,7 @@ impl Parse for str {
     /// assert_eq!(parser.after("to "), "3");
     /// ```
     #[inline]
-    fn as_parser(&self) -> Parser {
+    fn as_parser(&self) -> Parser<'_> {
         Parser::new(self)
     }
 }
@@ -325,7 +327,7 @@ where
     /// assert_eq!(ints.next(), Some(45));
     /// assert_eq!(ints.next(), None);
     /// ```
-    fn ints_iter<T: FromStrUnwrap>(&self) -> Ints<T> {
+    fn ints_iter<T: FromStrUnwrap>(&self) -> Ints<'_, T> {
         self.as_ref().ints_iter()
     }
 
@@ -373,7 +375,7 @@ where
     /// assert_eq!(ints.next(), Some(45));
     /// assert_eq!(ints.next(), None);
     /// ```
-    fn uints_iter<T: FromStrUnwrap>(&self) -> UInts<T> {
+    fn uints_iter<T: FromStrUnwrap>(&self) -> UInts<'_, T> {
         self.as_ref().uints_iter()
     }
 
@@ -461,7 +463,7 @@ where
     /// assert_eq!(parser.between("from ", " "), "271");
     /// assert_eq!(parser.after("to "), "3");
     /// ```
-    fn as_parser(&self) -> Parser {
+    fn as_parser(&self) -> Parser<'_> {
         self.as_ref().as_parser()
     }
 }
@@ -801,4 +803,61 @@ impl<'a> Parser<'a> {
         };
         self.before(suffix)
     }
+
+    /// Returns the next token of the string: the next run of ASCII letters, digits and underscores,
+    /// or `None` if there isn't one.
+    ///
+    /// Future method calls on `self` will then work on the remainder of the string after the token.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::Parse;
+    ///
+    /// let s = "broadcaster -> a, b_2";
+    /// let mut parser = s.as_parser();
+    ///
+    /// assert_eq!(parser.try_token(), Some("broadcaster"));
+    /// assert_eq!(parser.try_token(), Some("a"));
+    /// assert_eq!(parser.try_token(), Some("b_2"));
+    /// assert_eq!(parser.try_token(), None);
+    /// ```
+    pub fn try_token(&mut self) -> Option<&'a str> {
+        let is_token = |c: char| c.is_ascii_alphanumeric() || c == '_';
+        let start = self.inner.find(is_token)?;
+        let rest = &self.inner[start..];
+        let len = rest.find(|c| !is_token(c)).unwrap_or(rest.len());
+        self.inner = &rest[len..];
+        Some(&rest[..len])
+    }
+
+    /// Returns the next token of the string: the next run of ASCII letters, digits and underscores.
+    ///
+    /// Future method calls on `self` will then work on the remainder of the string after the token.
+    ///
+    /// Panics if there are no more tokens.
+    #[inline]
+    #[track_caller]
+    pub fn token(&mut self) -> &'a str {
+        self.try_token()
+            .expect("the string should contain another token")
+    }
+
+    /// Interns the next token of the string with `interner` and returns its id.
+    ///
+    /// Future method calls on `self` will then work on the remainder of the string after the token.
+    ///
+    /// Panics if there are no more tokens.
+    #[inline]
+    #[track_caller]
+    pub fn intern(&mut self, interner: &mut Interner<'a>) -> u32 {
+        interner.intern(self.token())
+    }
+
+    /// Interns the next token of the string with `interner` and returns its id, or `None` if there are no more tokens.
+    ///
+    /// Future method calls on `self` will then work on the remainder of the string after the token.
+    #[inline]
+    pub fn try_intern(&mut self, interner: &mut Interner<'a>) -> Option<u32> {
+        self.try_token().map(|token| interner.intern(token))
+    }
 }
@@ -0,0 +1,192 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Dir4, Dir8};
+
+/// A position or offset on a 2D grid.
+///
+/// `x` increases to the right and `y` increases downwards, so `(0, 0)` is the top-left cell of a grid.
+///
+/// # Examples
+/// ```
+/// use aoc::{Dir4, Point};
+///
+/// let p = Point::new(3, -2);
+///
+/// assert_eq!(p.x, 3);
+/// assert_eq!(p.y, -2);
+/// assert_eq!(p + Point::new(1, 1), Point::new(4, -1));
+/// assert_eq!(p * 2, Point::new(6, -4));
+/// assert_eq!(p + Dir4::Down, Point::new(3, -1));
+/// assert_eq!(p.manhattan(Point::ZERO), 5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    /// The origin, `(0, 0)`.
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// Creates a new `Point`.
+    #[inline]
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the Manhattan (taxicab) distance between `self` and `other`.
+    #[inline]
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Returns the Chebyshev (king move) distance between `self` and `other`.
+    #[inline]
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Returns `self` with the absolute value of each component.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Returns `self` with the sign of each component.
+    #[inline]
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Returns `self` rotated 90 degrees clockwise about the origin (as displayed, with `y` downwards).
+    #[inline]
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Returns `self` rotated 90 degrees anticlockwise about the origin (as displayed, with `y` downwards).
+    #[inline]
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Returns the 4 orthogonally adjacent points, in the order of [`Dir4::ALL`].
+    ///
+    /// To only get the neighbours inside a grid, use [`Grid::neighbours4`](crate::Grid::neighbours4).
+    #[inline]
+    pub fn neighbours4(self) -> [Self; 4] {
+        Dir4::ALL.map(|dir| self + dir)
+    }
+
+    /// Returns the 8 orthogonally and diagonally adjacent points, in the order of [`Dir8::ALL`].
+    ///
+    /// To only get the neighbours inside a grid, use [`Grid::neighbours8`](crate::Grid::neighbours8).
+    #[inline]
+    pub fn neighbours8(self) -> [Self; 8] {
+        Dir8::ALL.map(|dir| self + dir)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    #[inline]
+    fn from((x, y): (i64, i64)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Point> for (i64, i64) {
+    #[inline]
+    fn from(p: Point) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Add<Dir4> for Point {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Dir4) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl Add<Dir8> for Point {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Dir8) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl AddAssign for Point {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<i64> for Point {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i64) {
+        *self = *self * rhs;
+    }
+}
+
+impl AddAssign<Dir4> for Point {
+    #[inline]
+    fn add_assign(&mut self, rhs: Dir4) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Dir8> for Point {
+    #[inline]
+    fn add_assign(&mut self, rhs: Dir8) {
+        *self = *self + rhs;
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{Dir8, Point};
+
+/// A simple polygon on the integer lattice, given by its vertices in order.
+///
+/// The last vertex is implicitly joined back to the first. Vertices may go round in either direction.
+///
+/// # Examples
+/// ```
+/// use aoc::{Dir4, Parse, Polygon};
+///
+/// // Lavaduct Lagoon: the trench and the lagoon inside it.
+/// let plan = "R 6 (#70c710)\nD 5 (#0dc571)\nL 2 (#5713f0)\nD 2 (#d2c081)\nR 2 (#59c680)\n\
+///             D 2 (#411b91)\nL 5 (#8ceee2)\nU 2 (#caa173)\nL 1 (#1b58a2)\nU 2 (#caa171)\n\
+///             R 2 (#7807d2)\nU 3 (#a77fa3)\nL 2 (#015232)\nU 2 (#7a21e3)";
+/// let moves = plan.lines().map(|line| {
+///     let dir = Dir4::from_byte(line.idx(0));
+///     let distance: i64 = line.as_parser().skip(2).before(" ").parse_uw();
+///     (dir, distance)
+/// });
+/// let lagoon = Polygon::from_moves(moves);
+///
+/// assert_eq!(lagoon.area(), 42);
+/// assert_eq!(lagoon.boundary(), 38);
+/// assert_eq!(lagoon.interior(), 24);
+/// assert_eq!(lagoon.lattice_points(), 62);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Creates a new `Polygon` from its vertices in order.
+    #[inline]
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    /// Creates a new `Polygon` by following `(direction, distance)` moves from the origin.
+    ///
+    /// The moves should end back at the origin. See [`PolygonBuilder`] to start elsewhere or to build it up step by step.
+    pub fn from_moves<D: Into<Dir8>>(moves: impl IntoIterator<Item = (D, i64)>) -> Self {
+        let mut builder = PolygonBuilder::new(Point::ZERO);
+        for (dir, distance) in moves {
+            builder.step(dir, distance);
+        }
+        builder.build()
+    }
+
+    /// Returns the vertices in order.
+    #[inline(always)]
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Returns an iterator over the edges as `(start, end)` pairs, including the closing edge.
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Returns twice the signed area, using the shoelace formula.
+    ///
+    /// Twice the area is always an integer. It is positive if the vertices go clockwise with `y` increasing downwards
+    /// (anticlockwise with `y` increasing upwards), and negative otherwise.
+    pub fn signed_area_2(&self) -> i64 {
+        self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+    }
+
+    /// Returns the area enclosed by the edges, rounded down.
+    ///
+    /// This treats vertices as points, not cells: the unit square `(0,0), (1,0), (1,1), (0,1)` has area 1.
+    #[inline]
+    pub fn area(&self) -> i64 {
+        self.signed_area_2().abs() / 2
+    }
+
+    /// Returns the number of lattice points on the edges.
+    ///
+    /// For a polygon whose edges are all horizontal, vertical or diagonal, this is also the length of the boundary in steps.
+    pub fn boundary(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x).abs(), (b.y - a.y).abs()))
+            .sum()
+    }
+
+    /// Returns the number of lattice points strictly inside the polygon, using Pick's theorem.
+    ///
+    /// When vertices are the centres of cells, as in a loop of pipes, this is the number of enclosed cells.
+    #[inline]
+    pub fn interior(&self) -> i64 {
+        (self.signed_area_2().abs() - self.boundary() + 2) / 2
+    }
+
+    /// Returns the number of lattice points inside the polygon or on its edges.
+    ///
+    /// When vertices are the centres of cells, as in a dug trench, this is the number of cells covered.
+    #[inline]
+    pub fn lattice_points(&self) -> i64 {
+        self.interior() + self.boundary()
+    }
+
+    /// Returns whether `point` lies on one of the edges.
+    pub fn on_boundary(&self, point: Point) -> bool {
+        self.edges().any(|(a, b)| {
+            let (d, p) = (b - a, point - a);
+            d.x * p.y == d.y * p.x
+                && a.x.min(b.x) <= point.x
+                && point.x <= a.x.max(b.x)
+                && a.y.min(b.y) <= point.y
+                && point.y <= a.y.max(b.y)
+        })
+    }
+
+    /// Returns whether `point` is strictly inside the polygon.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{Point, Polygon};
+    ///
+    /// let square = Polygon::new(vec![Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4)]);
+    ///
+    /// assert!(square.contains(Point::new(2, 3)));
+    /// assert!(!square.contains(Point::new(4, 2)));
+    /// assert!(square.on_boundary(Point::new(4, 2)));
+    /// assert!(!square.contains(Point::new(5, 2)));
+    /// ```
+    pub fn contains(&self, point: Point) -> bool {
+        if self.on_boundary(point) {
+            return false;
+        }
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if (a.y <= point.y) != (b.y <= point.y) {
+                // Which side of the edge the point is on, relative to the edge's vertical direction.
+                let cross = (b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y);
+                if (cross > 0) == (b.y > a.y) {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+impl FromIterator<Point> for Polygon {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Builds a [`Polygon`] from a sequence of moves.
+///
+/// A vertex is only added when the direction changes, so consecutive moves in the same direction are merged.
+///
+/// # Examples
+/// ```
+/// use aoc::{Dir4, Point, PolygonBuilder};
+///
+/// let mut builder = PolygonBuilder::new(Point::new(1, 1));
+/// builder.step(Dir4::Right, 2).step(Dir4::Right, 1).step(Dir4::Down, 3);
+///
+/// assert_eq!(builder.position(), Point::new(4, 4));
+/// builder.step(Dir4::Left, 3).step(Dir4::Up, 3);
+///
+/// let square = builder.build();
+/// assert_eq!(square.vertices().len(), 4);
+/// assert_eq!(square.area(), 9);
+/// assert_eq!(square.lattice_points(), 16);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolygonBuilder {
+    vertices: Vec<Point>,
+    position: Point,
+    last: Option<Dir8>,
+}
+
+impl PolygonBuilder {
+    /// Creates a new `PolygonBuilder` starting at `start`.
+    #[inline]
+    pub fn new(start: Point) -> Self {
+        Self {
+            vertices: Vec::new(),
+            position: start,
+            last: None,
+        }
+    }
+
+    /// Moves `distance` steps in direction `dir`.
+    pub fn step(&mut self, dir: impl Into<Dir8>, distance: i64) -> &mut Self {
+        let dir = dir.into();
+        if self.last != Some(dir) {
+            self.vertices.push(self.position);
+            self.last = Some(dir);
+        }
+        self.position += dir.offset() * distance;
+        self
+    }
+
+    /// Returns the current position.
+    #[inline(always)]
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    /// Returns the polygon traced out so far, closed back to its start.
+    pub fn build(self) -> Polygon {
+        let mut vertices = self.vertices;
+        // Drop the start if the path goes straight through it.
+        if vertices.len() > 2 {
+            let (first, second, last) = (vertices[0], vertices[1], vertices[vertices.len() - 1]);
+            let (a, b) = (first - last, second - first);
+            if a.x * b.y == a.y * b.x && a.x * b.x + a.y * b.y > 0 {
+                vertices.remove(0);
+            }
+        }
+        Polygon::new(vertices)
+    }
+}
+
+fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
@@ -0,0 +1,146 @@
+//! Progress reporting for long-running parts.
+//!
+//! A part can call these functions as often as it likes: each call is a single relaxed atomic operation
+//! (or a short lock, for [`message`]). While a part is running under [`run`](crate::run), a background thread
+//! writes the current progress to `out/progress` every 100 milliseconds, if it has changed,
+//! so that `aocli` can render a progress bar. The file is removed once the part returns or panics.
+//!
+//! When nothing is running the part (in tests, for example), the calls still succeed and nothing is written.
+//!
+//! The `progress` file has three lines: the current count, the total (empty if no total has been set),
+//! and the latest message (empty if none has been set).
+//!
+//! # Examples
+//! ```
+//! use aoc::progress;
+//!
+//! let seeds = [79u64, 14, 55, 13];
+//! progress::set_total(seeds.len() as u64);
+//! progress::message("checking seeds");
+//! for _seed in seeds {
+//!     // ... expensive work ...
+//!     progress::inc();
+//! }
+//! ```
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+        Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+const UNSET: u64 = u64::MAX;
+const INTERVAL: Duration = Duration::from_millis(100);
+
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static TOTAL: AtomicU64 = AtomicU64::new(UNSET);
+static MESSAGE: Mutex<String> = Mutex::new(String::new());
+static MESSAGE_VERSION: AtomicU64 = AtomicU64::new(0);
+
+/// Sets the total amount of work, for rendering the progress as a fraction.
+#[inline]
+pub fn set_total(total: u64) {
+    TOTAL.store(total, Ordering::Relaxed);
+}
+
+/// Sets the amount of work done so far.
+#[inline]
+pub fn set(current: u64) {
+    CURRENT.store(current, Ordering::Relaxed);
+}
+
+/// Adds one to the amount of work done so far.
+#[inline]
+pub fn inc() {
+    add(1);
+}
+
+/// Adds `n` to the amount of work done so far.
+#[inline]
+pub fn add(n: u64) {
+    CURRENT.fetch_add(n, Ordering::Relaxed);
+}
+
+/// Sets a message describing what the part is currently doing.
+///
+/// Only the first line of `message` is reported.
+pub fn message(message: &str) {
+    let message = message.lines().next().unwrap_or("");
+    let mut current = MESSAGE.lock().unwrap_or_else(|e| e.into_inner());
+    current.clear();
+    current.push_str(message);
+    MESSAGE_VERSION.fetch_add(1, Ordering::Relaxed);
+}
+
+fn reset() {
+    CURRENT.store(0, Ordering::Relaxed);
+    TOTAL.store(UNSET, Ordering::Relaxed);
+    message("");
+}
+
+/// The background thread that flushes progress to a file while a part is running.
+///
+/// The thread is stopped and the file removed when the `Reporter` is dropped, including when the part panics.
+pub(crate) struct Reporter {
+    stop: Sender<()>,
+    handle: Option<JoinHandle<()>>,
+    path: PathBuf,
+}
+
+impl Reporter {
+    /// Resets the progress and starts flushing it to `path`.
+    pub fn start(path: PathBuf) -> Self {
+        reset();
+        let (stop, stopped) = mpsc::channel();
+        let thread_path = path.clone();
+        let handle = thread::spawn(move || {
+            let mut last = None;
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(INTERVAL) {
+                let snapshot = (
+                    CURRENT.load(Ordering::Relaxed),
+                    TOTAL.load(Ordering::Relaxed),
+                    MESSAGE_VERSION.load(Ordering::Relaxed),
+                );
+                if last == Some(snapshot) {
+                    continue;
+                }
+                last = Some(snapshot);
+                let (current, total, _) = snapshot;
+                let total = if total == UNSET {
+                    String::new()
+                } else {
+                    total.to_string()
+                };
+                let message = MESSAGE.lock().unwrap_or_else(|e| e.into_inner()).clone();
+                let _ = fs::write(&thread_path, format!("{current}\n{total}\n{message}"));
+            }
+        });
+        Self {
+            stop,
+            handle: Some(handle),
+            path,
+        }
+    }
+
+    /// Stops the background thread and removes the progress file.
+    #[inline]
+    pub fn finish(self) {
+        drop(self);
+    }
+}
+
+impl Drop for Reporter {
+    fn drop(&mut self) {
+        let _ = self.stop.send(());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        // Panicking here while the part is already panicking would abort, so a failed removal is ignored.
+        let _ = fs::remove_file(&self.path);
+    }
+}
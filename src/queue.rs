@@ -0,0 +1,329 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// A queue of items that pops the item with the smallest priority first.
+///
+/// Implemented by `BinaryHeap<Reverse<(P, T)>>`, which accepts any priorities, and by the faster [`BucketQueue`]
+/// and [`RadixHeap`], which only accept priorities no smaller than the last one popped, as in Dijkstra's algorithm.
+/// Used by [`search::dijkstra_with`](crate::search::dijkstra_with) and [`search::astar_with`](crate::search::astar_with).
+///
+/// Both monotone queues accept `usize` and `u64` priorities, so either can be used with the same costs.
+pub trait PriorityQueue<P, T>: Default {
+    /// Adds `item` to the queue with the given priority.
+    fn push(&mut self, priority: P, item: T);
+
+    /// Removes and returns an item with the smallest priority, with its priority, or `None` if the queue is empty.
+    fn pop(&mut self) -> Option<(P, T)>;
+
+    /// Returns the item that [`pop`](PriorityQueue::pop) would return next, with its priority, without removing it,
+    /// or `None` if the queue is empty.
+    fn peek(&self) -> Option<(P, &T)>;
+
+    /// Returns the number of items in the queue.
+    fn len(&self) -> usize;
+
+    /// Returns whether the queue is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every item from the queue.
+    fn clear(&mut self);
+}
+
+impl<P: Ord + Clone, T: Ord> PriorityQueue<P, T> for BinaryHeap<Reverse<(P, T)>> {
+    #[inline]
+    fn push(&mut self, priority: P, item: T) {
+        BinaryHeap::push(self, Reverse((priority, item)));
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<(P, T)> {
+        BinaryHeap::pop(self).map(|Reverse(entry)| entry)
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<(P, &T)> {
+        BinaryHeap::peek(self).map(|Reverse((priority, item))| (priority.clone(), item))
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        BinaryHeap::len(self)
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        BinaryHeap::clear(self);
+    }
+}
+
+/// A monotone priority queue with a bucket for each priority, as in Dial's algorithm.
+///
+/// The buckets form a ring that only covers the priorities between the last one popped and the largest one in the
+/// queue, so in a search whose steps cost at most `C` it holds about `C + 1` buckets, however long the distances get.
+/// Pushing and popping take constant time, plus the time to step over empty buckets. This suits searches where costs
+/// are small integers, so the distances in the queue are close together.
+///
+/// Panics if an item is pushed with a smaller priority than the last one popped.
+///
+/// # Examples
+/// ```
+/// use aoc::{BucketQueue, PriorityQueue};
+///
+/// let mut queue = BucketQueue::new();
+/// queue.push(3usize, 'c');
+/// queue.push(1usize, 'a');
+/// queue.push(2usize, 'b');
+///
+/// assert_eq!(queue.peek(), Some((1usize, &'a')));
+/// assert_eq!(queue.pop(), Some((1usize, 'a')));
+/// queue.push(1usize, 'd');
+/// assert_eq!(queue.pop(), Some((1usize, 'd')));
+/// assert_eq!(queue.peek(), Some((2usize, &'b')));
+/// assert_eq!(queue.len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BucketQueue<T> {
+    buckets: Vec<Vec<T>>,
+    current: usize,
+    len: usize,
+}
+
+impl<T> BucketQueue<T> {
+    /// Creates an empty `BucketQueue`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            buckets: Vec::new(),
+            current: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of items in the queue.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the queue is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes every item from the queue.
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    #[track_caller]
+    fn push_item(&mut self, priority: usize, item: T) {
+        if priority < self.current {
+            panic!("priority should not be less than the last priority popped");
+        }
+        let span = priority - self.current;
+        if span >= self.buckets.len() {
+            self.grow(span + 1);
+        }
+        let bucket = priority % self.buckets.len();
+        self.buckets[bucket].push(item);
+        self.len += 1;
+    }
+
+    fn pop_item(&mut self) -> Option<(usize, T)> {
+        if self.len == 0 {
+            return None;
+        }
+        let n = self.buckets.len();
+        while self.buckets[self.current % n].is_empty() {
+            self.current += 1;
+        }
+        self.len -= 1;
+        self.buckets[self.current % n]
+            .pop()
+            .map(|item| (self.current, item))
+    }
+
+    fn peek_item(&self) -> Option<(usize, &T)> {
+        if self.len == 0 {
+            return None;
+        }
+        let n = self.buckets.len();
+        (self.current..).find_map(|priority| {
+            self.buckets[priority % n]
+                .last()
+                .map(|item| (priority, item))
+        })
+    }
+
+    /// Resizes the ring to at least `size` buckets, moving every item to its bucket in the new ring.
+    fn grow(&mut self, size: usize) {
+        let size = size.next_power_of_two();
+        let mut buckets: Vec<Vec<T>> = (0..size).map(|_| Vec::new()).collect();
+        let n = self.buckets.len();
+        for priority in self.current..self.current + n {
+            buckets[priority % size] = std::mem::take(&mut self.buckets[priority % n]);
+        }
+        self.buckets = buckets;
+    }
+}
+
+impl<T> Default for BucketQueue<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A monotone priority queue that sorts items into buckets by the highest bit in which their priority differs from
+/// the last one popped.
+///
+/// Each item is moved between buckets at most 64 times, so this stays fast however far apart the priorities are.
+///
+/// Panics if an item is pushed with a smaller priority than the last one popped.
+///
+/// # Examples
+/// ```
+/// use aoc::{PriorityQueue, RadixHeap};
+///
+/// let mut queue = RadixHeap::new();
+/// queue.push(1_000_000u64, 'c');
+/// queue.push(5u64, 'a');
+/// queue.push(70u64, 'b');
+///
+/// assert_eq!(queue.pop(), Some((5u64, 'a')));
+/// assert_eq!(queue.peek(), Some((70u64, &'b')));
+/// assert_eq!(queue.pop(), Some((70u64, 'b')));
+/// queue.push(70u64, 'd');
+/// assert_eq!(queue.pop(), Some((70u64, 'd')));
+/// assert_eq!(queue.pop(), Some((1_000_000u64, 'c')));
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RadixHeap<T> {
+    buckets: [Vec<(u64, T)>; 65],
+    last: u64,
+    len: usize,
+}
+
+impl<T> RadixHeap<T> {
+    /// Creates an empty `RadixHeap`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| Vec::new()),
+            last: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of items in the queue.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the queue is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes every item from the queue.
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    #[inline(always)]
+    fn bucket(&self, priority: u64) -> usize {
+        (u64::BITS - (priority ^ self.last).leading_zeros()) as usize
+    }
+
+    #[track_caller]
+    fn push_item(&mut self, priority: u64, item: T) {
+        if priority < self.last {
+            panic!("priority should not be less than the last priority popped");
+        }
+        let bucket = self.bucket(priority);
+        self.buckets[bucket].push((priority, item));
+        self.len += 1;
+    }
+
+    fn pop_item(&mut self) -> Option<(u64, T)> {
+        if self.len == 0 {
+            return None;
+        }
+        if self.buckets[0].is_empty() {
+            // Move the smallest priority up to `last`, which spreads its bucket out into lower buckets.
+            let i = self.buckets.iter().position(|b| !b.is_empty()).unwrap();
+            let bucket = std::mem::take(&mut self.buckets[i]);
+            self.last = bucket.iter().map(|&(p, _)| p).min().unwrap();
+            for (priority, item) in bucket {
+                let j = self.bucket(priority);
+                self.buckets[j].push((priority, item));
+            }
+        }
+        self.len -= 1;
+        self.buckets[0].pop()
+    }
+
+    fn peek_item(&self) -> Option<(u64, &T)> {
+        let bucket = self.buckets.iter().find(|b| !b.is_empty())?;
+        // `pop` takes the last of the items with the smallest priority, once they have been moved to bucket 0.
+        bucket
+            .iter()
+            .rev()
+            .min_by_key(|&&(priority, _)| priority)
+            .map(|(priority, item)| (*priority, item))
+    }
+}
+
+impl<T> Default for RadixHeap<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! impl_monotone_queue {
+    ($queue:ident, $inner:ty, $($p:ty),*) => {
+        $(
+            impl<T> PriorityQueue<$p, T> for $queue<T> {
+                /// Panics if `priority` is less than the last priority popped.
+                #[inline]
+                #[track_caller]
+                fn push(&mut self, priority: $p, item: T) {
+                    let priority = <$inner>::try_from(priority).expect("priority should fit in the queue");
+                    self.push_item(priority, item);
+                }
+
+                #[inline]
+                fn pop(&mut self) -> Option<($p, T)> {
+                    self.pop_item().map(|(priority, item)| (priority as $p, item))
+                }
+
+                #[inline]
+                fn peek(&self) -> Option<($p, &T)> {
+                    self.peek_item().map(|(priority, item)| (priority as $p, item))
+                }
+
+                #[inline(always)]
+                fn len(&self) -> usize {
+                    $queue::len(self)
+                }
+
+                #[inline]
+                fn clear(&mut self) {
+                    $queue::clear(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_monotone_queue!(BucketQueue, usize, usize, u64);
+impl_monotone_queue!(RadixHeap, u64, usize, u64);
@@ -0,0 +1,189 @@
+use crate::{Integer, IntervalSet};
+
+/// A piecewise map of integers that shifts each of a set of source ranges by its own offset,
+/// and leaves every other integer unchanged.
+///
+/// This is the `destination source length` table of an almanac. Maps can be chained with [`RangeMap::then`]
+/// and applied to whole sets of ranges with [`RangeMap::map_set`].
+///
+/// # Examples
+/// ```
+/// use aoc::{IntervalSet, Parse, RangeMap};
+///
+/// let almanac = "seeds: 79 14 55 13
+///
+/// seed-to-soil map:
+/// 50 98 2
+/// 52 50 48
+///
+/// soil-to-fertilizer map:
+/// 0 15 37
+/// 37 52 2
+/// 39 0 15
+///
+/// fertilizer-to-water map:
+/// 49 53 8
+/// 0 11 42
+/// 42 0 7
+/// 57 7 4
+///
+/// water-to-light map:
+/// 88 18 7
+/// 18 25 70
+///
+/// light-to-temperature map:
+/// 45 77 23
+/// 81 45 19
+/// 68 64 13
+///
+/// temperature-to-humidity map:
+/// 0 69 1
+/// 1 0 69
+///
+/// humidity-to-location map:
+/// 60 56 37
+/// 56 93 4";
+/// let mut sections = almanac.split("\n\n");
+/// let seeds: Vec<u64> = sections.next().unwrap().uints_iter().collect();
+/// let location = sections
+///     .map(|section| section.lines().skip(1).map(|line| line.uints::<3, u64>()).collect())
+///     .fold(RangeMap::new(), |map, stage| map.then(&stage));
+///
+/// assert_eq!(seeds.iter().map(|&seed| location.get(seed)).min(), Some(35));
+///
+/// let seed_ranges: IntervalSet<u64> = seeds.chunks(2).map(|c| c[0]..=c[0] + c[1] - 1).collect();
+/// assert_eq!(location.map_set(&seed_ranges).min(), Some(46));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeMap<T = i64> {
+    /// Disjoint `(source start, source end, destination start)` pieces, sorted by source.
+    pieces: Vec<(T, T, T)>,
+}
+
+impl<T: Integer> RangeMap<T> {
+    /// Creates a new `RangeMap` that leaves every integer unchanged.
+    #[inline]
+    pub fn new() -> Self {
+        Self { pieces: Vec::new() }
+    }
+
+    /// Maps the `len` integers starting at `source` to the `len` integers starting at `dest`.
+    ///
+    /// Panics if the source range overlaps one that is already mapped.
+    #[track_caller]
+    pub fn insert(&mut self, dest: T, source: T, len: T) {
+        if len == T::ZERO {
+            return;
+        }
+        let end = source + (len - T::ONE);
+        let i = self.pieces.partition_point(|&(_, e, _)| e < source);
+        if self.pieces.get(i).is_some_and(|&(s, _, _)| s <= end) {
+            panic!("source ranges should not overlap");
+        }
+        self.pieces.insert(i, (source, end, dest));
+    }
+
+    /// Returns the integer that `value` maps to.
+    #[inline]
+    pub fn get(&self, value: T) -> T {
+        let i = self.pieces.partition_point(|&(_, e, _)| e < value);
+        match self.pieces.get(i) {
+            Some(&(s, _, d)) if s <= value => shift(value, s, d),
+            _ => value,
+        }
+    }
+
+    /// Returns the integers that the integers in `set` map to, splitting ranges where the map's pieces begin and end.
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let segments = self.segments();
+        let mut out = IntervalSet::new();
+        for range in set.iter() {
+            let (start, end) = range.into_inner();
+            let i = segments.partition_point(|&(_, e, _)| e < start);
+            for &(s, e, d) in segments[i..].iter().take_while(|&&(s, _, _)| s <= end) {
+                let (a, b) = (start.max(s), end.min(e));
+                out.insert(shift(a, s, d)..=shift(b, s, d));
+            }
+        }
+        out
+    }
+
+    /// Returns the map that applies `self` and then `next`.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::RangeMap;
+    ///
+    /// let a: RangeMap = [[100, 0, 10]].into_iter().collect();
+    /// let b: RangeMap = [[0, 105, 10], [-5, 5, 5]].into_iter().collect();
+    /// let ab = a.then(&b);
+    ///
+    /// assert!((-20..200).all(|x| ab.get(x) == b.get(a.get(x))));
+    /// assert_eq!(ab.get(7), 2);
+    /// assert_eq!(ab.get(12), 12);
+    /// ```
+    pub fn then(&self, next: &Self) -> Self {
+        let next_segments = next.segments();
+        let mut pieces = Vec::new();
+        for (s, e, d) in self.segments() {
+            let (start, end) = (shift(s, s, d), shift(e, s, d));
+            let i = next_segments.partition_point(|&(_, ne, _)| ne < start);
+            for &(ns, ne, nd) in next_segments[i..]
+                .iter()
+                .take_while(|&&(ns, _, _)| ns <= end)
+            {
+                let (a, b) = (start.max(ns), end.min(ne));
+                let (source, dest) = (shift(a, start, s), shift(a, ns, nd));
+                if source != dest {
+                    pieces.push((source, shift(b, start, s), dest));
+                }
+            }
+        }
+        Self { pieces }
+    }
+
+    /// Returns an iterator over the mapped pieces as `[dest, source, len]`, sorted by source.
+    pub fn iter(&self) -> impl Iterator<Item = [T; 3]> + '_ {
+        self.pieces.iter().map(|&(s, e, d)| [d, s, e - s + T::ONE])
+    }
+
+    /// Returns disjoint `(start, end, dest)` segments covering every integer, with unmapped gaps mapped to themselves.
+    fn segments(&self) -> Vec<(T, T, T)> {
+        let mut segments = Vec::with_capacity(2 * self.pieces.len() + 1);
+        let mut next = Some(T::MIN);
+        for &(s, e, d) in &self.pieces {
+            if let Some(gap) = next.filter(|&gap| gap < s) {
+                segments.push((gap, s - T::ONE, gap));
+            }
+            segments.push((s, e, d));
+            next = (e < T::MAX).then(|| e + T::ONE);
+        }
+        if let Some(gap) = next {
+            segments.push((gap, T::MAX, gap));
+        }
+        segments
+    }
+}
+
+impl<T: Integer> FromIterator<[T; 3]> for RangeMap<T> {
+    /// Creates a map from `[dest, source, len]` rows, as parsed with `uints::<3, _>()`.
+    fn from_iter<I: IntoIterator<Item = [T; 3]>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for [dest, source, len] in iter {
+            map.insert(dest, source, len);
+        }
+        map
+    }
+}
+
+/// Returns `value` moved by the offset from `from` to `to`, without overflowing for unsigned types.
+#[inline]
+fn shift<T: Integer>(value: T, from: T, to: T) -> T {
+    if from == to {
+        value
+    } else if from < to {
+        value + (to - from)
+    } else {
+        value - (from - to)
+    }
+}
@@ -0,0 +1,156 @@
+use std::{collections::HashMap, ops::Mul};
+
+use crate::Vec3;
+
+/// One of the 24 rotations of 3D space that map the axes onto the axes,
+/// represented as an integer matrix.
+///
+/// These are the orientations a cube can be placed in: any axis can face forwards, in either direction,
+/// and then there are 4 ways to turn about it. Reflections are not included.
+///
+/// `a * b` is the rotation that applies `b` first and then `a`.
+///
+/// # Examples
+/// ```
+/// use aoc::{Rotation, Vec3};
+///
+/// let all = Rotation::all();
+/// let v = Vec3::new([1, 2, 3]);
+///
+/// assert_eq!(all.len(), 24);
+/// assert!(all.iter().all(|&r| r.inverse() * r == Rotation::IDENTITY));
+/// assert!(all.iter().all(|&r| (r * v).0.map(i64::abs).iter().sum::<i64>() == 6));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rotation {
+    matrix: [[i64; 3]; 3],
+}
+
+impl Rotation {
+    /// The rotation that leaves every vector unchanged.
+    pub const IDENTITY: Self = Self {
+        matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    /// Returns all 24 rotations, starting with the identity.
+    pub fn all() -> [Self; 24] {
+        let mut all = [Self::IDENTITY; 24];
+        let mut i = 0;
+        for perm in [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ] {
+            for signs in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, &col) in perm.iter().enumerate() {
+                    matrix[row][col] = if signs >> row & 1 == 0 { 1 } else { -1 };
+                }
+                let rotation = Self { matrix };
+                if rotation.determinant() == 1 {
+                    all[i] = rotation;
+                    i += 1;
+                }
+            }
+        }
+        all
+    }
+
+    /// Returns the rotation as a matrix, indexed by `[row][column]`.
+    #[inline(always)]
+    pub fn matrix(self) -> [[i64; 3]; 3] {
+        self.matrix
+    }
+
+    /// Returns the rotation that undoes `self`.
+    #[inline]
+    pub fn inverse(self) -> Self {
+        let m = self.matrix;
+        Self {
+            matrix: [0, 1, 2].map(|row| [0, 1, 2].map(|col| m[col][row])),
+        }
+    }
+
+    /// Returns `v` rotated by `self`.
+    #[inline]
+    pub fn apply(self, v: Vec3) -> Vec3 {
+        Vec3::new(self.matrix.map(|row| Vec3::new(row).dot(v)))
+    }
+
+    /// Returns every vector in `points` rotated by `self`.
+    pub fn apply_all(self, points: &[Vec3]) -> Vec<Vec3> {
+        points.iter().map(|&p| self.apply(p)).collect()
+    }
+
+    /// Finds a rotation and translation that map at least `k` of the points in `points` onto points in `reference`.
+    ///
+    /// Returns `(rotation, translation)` such that `rotation * p + translation` is in `reference`
+    /// for at least `k` points `p` in `points`, or `None` if there is no such alignment.
+    /// Rotations are tried in the order of [`Rotation::all`].
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::{Rotation, Vec3};
+    ///
+    /// let reference = [[0, 0, 0], [1, 0, 0], [0, 2, 0], [0, 0, 3]].map(Vec3::new);
+    /// let rotation = Rotation::all()[7];
+    /// let offset = Vec3::new([10, -4, 2]);
+    /// let points: Vec<_> = reference.iter().map(|&p| rotation.inverse() * (p - offset)).collect();
+    ///
+    /// assert_eq!(Rotation::align(&reference, &points, 4), Some((rotation, offset)));
+    /// ```
+    pub fn align(reference: &[Vec3], points: &[Vec3], k: usize) -> Option<(Self, Vec3)> {
+        let mut counts = HashMap::new();
+        for rotation in Self::all() {
+            counts.clear();
+            for &p in points {
+                let p = rotation * p;
+                for &r in reference {
+                    let count = counts.entry(r - p).or_insert(0);
+                    *count += 1;
+                    if *count >= k {
+                        return Some((rotation, r - p));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn determinant(self) -> i64 {
+        let [a, b, c] = self.matrix.map(Vec3::new);
+        a.dot(b.cross(c))
+    }
+}
+
+impl Default for Rotation {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Rotation {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.matrix, rhs.matrix);
+        Self {
+            matrix: [0, 1, 2]
+                .map(|row| [0, 1, 2].map(|col| (0..3).map(|k| a[row][k] * b[k][col]).sum())),
+        }
+    }
+}
+
+impl Mul<Vec3> for Rotation {
+    type Output = Vec3;
+
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        self.apply(rhs)
+    }
+}
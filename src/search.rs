@@ -0,0 +1,687 @@
+//! Shortest-path searches over implicit graphs.
+//!
+//! Each search takes a start state, a closure returning the successors of a state, and a goal predicate,
+//! and returns a [`Search`] holding the distance to the goal, the path to it, and the distance to every state
+//! settled on the way. The `_all` variants keep every optimal route instead, as an [`AllPaths`].
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use crate::{Integer, PriorityQueue};
+
+/// Marks a state with no parent.
+const NONE: usize = usize::MAX;
+
+/// The result of a [`bfs`], [`dijkstra`] or [`astar`] search.
+///
+/// Holds the goal that was reached, if any, and the distance and parent of every state that was settled along the way,
+/// so paths can be rebuilt to any of them.
+#[derive(Clone, Debug)]
+pub struct Search<S, C> {
+    index: HashMap<S, usize>,
+    nodes: Vec<Node<S, C>>,
+    goals: Vec<usize>,
+    expanded: usize,
+    all: bool,
+}
+
+#[derive(Clone, Debug)]
+struct Node<S, C> {
+    state: S,
+    distance: C,
+    parent: usize,
+    /// Further parents on equally short routes, only kept when searching for all paths.
+    others: Vec<usize>,
+    settled: bool,
+}
+
+impl<S: Clone + Eq + Hash, C: Integer> Search<S, C> {
+    fn new(start: S, all: bool) -> Self {
+        Self {
+            index: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![Node {
+                state: start,
+                distance: C::ZERO,
+                parent: NONE,
+                others: Vec::new(),
+                settled: false,
+            }],
+            goals: Vec::new(),
+            expanded: 0,
+            all,
+        }
+    }
+
+    /// Records a route to `state` through `parent`, returning its index if it is shorter than any found before.
+    fn relax(&mut self, state: S, distance: C, parent: usize) -> Option<usize> {
+        match self.index.get(&state) {
+            Some(&i) => {
+                let node = &mut self.nodes[i];
+                if !node.settled && distance < node.distance {
+                    node.distance = distance;
+                    node.parent = parent;
+                    node.others.clear();
+                    Some(i)
+                } else {
+                    // The same route can be offered twice, by a repeated successor or a parallel edge,
+                    // and must only be counted once.
+                    if self.all
+                        && distance == node.distance
+                        && node.parent != NONE
+                        && parent != node.parent
+                        && !node.others.contains(&parent)
+                    {
+                        node.others.push(parent);
+                    }
+                    None
+                }
+            }
+            None => {
+                let i = self.nodes.len();
+                self.index.insert(state.clone(), i);
+                self.nodes.push(Node {
+                    state,
+                    distance,
+                    parent,
+                    others: Vec::new(),
+                    settled: false,
+                });
+                Some(i)
+            }
+        }
+    }
+
+    /// Returns the goal state that was reached, or `None` if no goal was reachable.
+    #[inline]
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first().map(|&i| &self.nodes[i].state)
+    }
+
+    /// Returns the distance from the start to the goal, or `None` if no goal was reachable.
+    #[inline]
+    pub fn distance(&self) -> Option<C> {
+        self.goals.first().map(|&i| self.nodes[i].distance)
+    }
+
+    /// Returns the states on a shortest path from the start to the goal, inclusive,
+    /// or `None` if no goal was reachable.
+    #[inline]
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goals.first().map(|&i| self.path_from(i))
+    }
+
+    /// Returns the shortest distance from the start to `state`, or `None` if `state` was not settled by the search.
+    #[inline]
+    pub fn distance_to(&self, state: &S) -> Option<C> {
+        self.settled(state).map(|i| self.nodes[i].distance)
+    }
+
+    /// Returns the states on a shortest path from the start to `state`, inclusive,
+    /// or `None` if `state` was not settled by the search.
+    #[inline]
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.settled(state).map(|i| self.path_from(i))
+    }
+
+    /// Returns an iterator over every settled state and its shortest distance from the start, in the order they were
+    /// first reached.
+    ///
+    /// If the search ran until no states were left, this is every state reachable from the start.
+    pub fn distances(&self) -> impl Iterator<Item = (&S, C)> {
+        self.nodes
+            .iter()
+            .filter(|node| node.settled)
+            .map(|node| (&node.state, node.distance))
+    }
+
+    /// Returns the number of states that were expanded, that is, whose successors were generated.
+    #[inline(always)]
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+
+    fn settled(&self, state: &S) -> Option<usize> {
+        self.index
+            .get(state)
+            .copied()
+            .filter(|&i| self.nodes[i].settled)
+    }
+
+    fn path_from(&self, mut i: usize) -> Vec<S> {
+        let mut path = Vec::new();
+        while i != NONE {
+            path.push(self.nodes[i].state.clone());
+            i = self.nodes[i].parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Returns the parents of node `i` on shortest routes.
+    fn predecessors(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        let node = &self.nodes[i];
+        (node.parent != NONE)
+            .then_some(node.parent)
+            .into_iter()
+            .chain(node.others.iter().copied())
+    }
+
+    /// Returns whether the search should stop before settling a state at `distance`, having already found the goals.
+    fn done(&self, distance: C) -> bool {
+        self.goals
+            .first()
+            .is_some_and(|&g| !self.all || distance > self.nodes[g].distance)
+    }
+}
+
+/// Finds a shortest path from `start` to a state satisfying `goal`, where every step costs 1, using a breadth-first
+/// search.
+///
+/// `successors` returns the states one step away from a state. Use a `goal` that always returns `false` to find the
+/// distance to every reachable state.
+///
+/// # Examples
+/// ```
+/// use aoc::{search, Grid};
+///
+/// let maze = Grid::from_input(["S.#.....", ".##.###.", "....#..E", "#.#...#."]);
+/// let start = maze.position(&b'S').unwrap();
+/// let end = maze.position(&b'E').unwrap();
+///
+/// let found = search::bfs(
+///     start,
+///     |&p| maze.neighbours4(p).filter(|&q| maze[q] != b'#'),
+///     |&p| p == end,
+/// );
+///
+/// assert_eq!(found.distance(), Some(11));
+/// assert_eq!(found.path().unwrap().len(), 12);
+/// assert!(found.expanded() <= 22);
+///
+/// let all = search::bfs(start, |&p| maze.neighbours4(p).filter(|&q| maze[q] != b'#'), |_| false);
+/// assert_eq!(all.distances().count(), 22);
+/// assert_eq!(all.distances().map(|(_, d)| d).max(), Some(12));
+/// ```
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_in(Search::new(start, false), successors, goal)
+}
+
+fn bfs_in<S, I>(
+    mut search: Search<S, usize>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    search.nodes[0].settled = true;
+    let mut queue = VecDeque::from([0]);
+    let mut expanded = Vec::new();
+    while let Some(i) = queue.pop_front() {
+        if search.done(search.nodes[i].distance) {
+            break;
+        }
+        if goal(&search.nodes[i].state) {
+            search.goals.push(i);
+            continue;
+        }
+        search.expanded += 1;
+        let distance = search.nodes[i].distance + 1;
+        expanded.clear();
+        expanded.extend(successors(&search.nodes[i].state));
+        for next in expanded.drain(..) {
+            if let Some(j) = search.relax(next, distance, i) {
+                search.nodes[j].settled = true;
+                queue.push_back(j);
+            }
+        }
+    }
+    search
+}
+
+/// Finds a shortest path from `start` to a state satisfying `goal`, using Dijkstra's algorithm.
+///
+/// `successors` returns `(state, cost)` pairs for the states one step away from a state. Costs must not be negative.
+/// Use a `goal` that always returns `false` to find the distance to every reachable state.
+///
+/// # Examples
+/// ```
+/// use aoc::{search, Grid, Point};
+///
+/// // Chiton: the lowest total risk from the top left to the bottom right.
+/// let risk = Grid::from_input_map(["1163751", "1381373", "2136511", "3694931", "7463417"], |b| (b - b'0') as u32);
+/// let end = Point::new(6, 4);
+///
+/// let found = search::dijkstra(
+///     Point::ZERO,
+///     |&p| risk.neighbours4(p).map(|q| (q, risk[q])),
+///     |&p| p == end,
+/// );
+///
+/// assert_eq!(found.distance(), Some(28));
+/// assert_eq!(found.path().unwrap().first(), Some(&Point::ZERO));
+/// assert_eq!(found.goal(), Some(&end));
+/// ```
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::ZERO, goal)
+}
+
+/// Finds a shortest path from `start` to a state satisfying `goal`, using A* search.
+///
+/// `successors` returns `(state, cost)` pairs for the states one step away from a state. Costs must not be negative.
+/// `heuristic` estimates the remaining distance from a state to the nearest goal. For the result to be a shortest
+/// path, it must never overestimate, and must not drop by more than the cost of any step.
+///
+/// # Examples
+/// ```
+/// use aoc::{search, Grid, Point};
+///
+/// let risk = Grid::from_input_map(["1163751", "1381373", "2136511", "3694931", "7463417"], |b| (b - b'0') as i64);
+/// let end = Point::new(6, 4);
+/// let successors = |&p: &Point| risk.neighbours4(p).map(|q| (q, risk[q])).collect::<Vec<_>>();
+///
+/// let dijkstra = search::dijkstra(Point::ZERO, successors, |&p| p == end);
+/// let astar = search::astar(Point::ZERO, successors, |&p| p.manhattan(end), |&p| p == end);
+///
+/// assert_eq!(astar.distance(), Some(28));
+/// assert!(astar.expanded() <= dijkstra.expanded());
+/// ```
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_with(
+        start,
+        successors,
+        heuristic,
+        goal,
+        BinaryHeap::<Reverse<_>>::new(),
+    )
+}
+
+/// Finds a shortest path from `start` to a state satisfying `goal`, using Dijkstra's algorithm with the given
+/// priority queue.
+///
+/// Like [`dijkstra`], but a [`BucketQueue`](crate::BucketQueue) or [`RadixHeap`](crate::RadixHeap) can be used
+/// instead of a binary heap, which is often faster when costs are small integers.
+///
+/// # Examples
+/// ```
+/// use aoc::{search, BucketQueue, Grid, Point, RadixHeap};
+///
+/// let risk = Grid::from_input(["1163751", "1381373", "2136511", "3694931", "7463417"]);
+/// let end = Point::new(6, 4);
+///
+/// let successors = |&p: &Point| risk.neighbours4(p).map(|q| (q, (risk[q] - b'0') as u64));
+/// let bucket = search::dijkstra_with(Point::ZERO, successors, |&p| p == end, BucketQueue::new());
+/// let radix = search::dijkstra_with(Point::ZERO, successors, |&p| p == end, RadixHeap::new());
+///
+/// assert_eq!(bucket.distance(), Some(28));
+/// assert_eq!(radix.distance(), Some(28));
+/// ```
+pub fn dijkstra_with<S, C, I, Q>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+    queue: Q,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+    Q: PriorityQueue<C, usize>,
+{
+    astar_with(start, successors, |_| C::ZERO, goal, queue)
+}
+
+/// Finds a shortest path from `start` to a state satisfying `goal`, using A* search with the given priority queue.
+///
+/// Like [`astar`], but a [`BucketQueue`](crate::BucketQueue) or [`RadixHeap`](crate::RadixHeap) can be used
+/// instead of a binary heap. These need the heuristic to be consistent, as described for [`astar`].
+pub fn astar_with<S, C, I, Q>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    goal: impl FnMut(&S) -> bool,
+    queue: Q,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+    Q: PriorityQueue<C, usize>,
+{
+    astar_in(
+        Search::new(start, false),
+        successors,
+        heuristic,
+        goal,
+        queue,
+    )
+}
+
+fn astar_in<S, C, I, Q>(
+    mut search: Search<S, C>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+    mut queue: Q,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+    Q: PriorityQueue<C, usize>,
+{
+    queue.clear();
+    queue.push(heuristic(&search.nodes[0].state), 0);
+    let mut expanded = Vec::new();
+    while let Some((estimate, i)) = queue.pop() {
+        if search.nodes[i].settled {
+            continue;
+        }
+        if search.done(estimate) {
+            break;
+        }
+        search.nodes[i].settled = true;
+        if goal(&search.nodes[i].state) {
+            search.goals.push(i);
+            continue;
+        }
+        search.expanded += 1;
+        let distance = search.nodes[i].distance;
+        expanded.clear();
+        expanded.extend(successors(&search.nodes[i].state));
+        for (next, cost) in expanded.drain(..) {
+            let estimate = heuristic(&next);
+            if let Some(j) = search.relax(next, distance + cost, i) {
+                queue.push(distance + cost + estimate, j);
+            }
+        }
+    }
+    search
+}
+
+/// The result of a [`bfs_all`] or [`dijkstra_all`] search, keeping every shortest path rather than just one.
+///
+/// Every settled state remembers all of its predecessors on shortest routes from the start, forming a DAG that can
+/// be counted, enumerated, or walked back from the goals.
+#[derive(Clone, Debug)]
+pub struct AllPaths<S, C> {
+    search: Search<S, C>,
+}
+
+impl<S: Clone + Eq + Hash, C: Integer> AllPaths<S, C> {
+    /// Returns an iterator over every goal state at the shortest distance from the start.
+    pub fn goals(&self) -> impl Iterator<Item = &S> {
+        self.search
+            .goals
+            .iter()
+            .map(|&i| &self.search.nodes[i].state)
+    }
+
+    /// Returns the distance from the start to the nearest goals, or `None` if no goal was reachable.
+    #[inline]
+    pub fn distance(&self) -> Option<C> {
+        self.search.distance()
+    }
+
+    /// Returns the shortest distance from the start to `state`, or `None` if `state` was not settled by the search.
+    #[inline]
+    pub fn distance_to(&self, state: &S) -> Option<C> {
+        self.search.distance_to(state)
+    }
+
+    /// Returns the number of states that were expanded, that is, whose successors were generated.
+    #[inline(always)]
+    pub fn expanded(&self) -> usize {
+        self.search.expanded
+    }
+
+    /// Returns an iterator over the states just before `state` on the shortest paths to it.
+    ///
+    /// The iterator is empty if `state` is the start or was not settled by the search.
+    pub fn predecessors(&self, state: &S) -> impl Iterator<Item = &S> {
+        self.search
+            .settled(state)
+            .into_iter()
+            .flat_map(|i| self.search.predecessors(i))
+            .map(|i| &self.search.nodes[i].state)
+    }
+
+    /// Returns the number of distinct shortest paths from the start to any of the goals.
+    ///
+    /// A successor that is yielded more than once, such as through a parallel edge, is only counted once.
+    ///
+    /// Panics if the count does not fit in a `u128`.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc::search;
+    ///
+    /// let found = search::bfs_all(0, |&i| [i + 1, i + 1], |&i| i == 2);
+    /// assert_eq!(found.count_paths(), 1);
+    /// assert_eq!(found.paths().collect::<Vec<_>>(), [vec![0, 1, 2]]);
+    ///
+    /// let found = search::dijkstra_all(0, |&i| [(i + 1, 3), (i + 1, 3), (i + 2, 6)], |&i| i == 2);
+    /// assert_eq!(found.distance(), Some(6));
+    /// assert_eq!(found.count_paths(), 2);
+    /// ```
+    #[track_caller]
+    pub fn count_paths(&self) -> u128 {
+        self.count(|a, b| a.checked_add(b).expect("path count should fit in a u128"))
+    }
+
+    /// Returns the number of distinct shortest paths from the start to any of the goals, modulo `modulus`.
+    pub fn count_paths_mod(&self, modulus: u64) -> u64 {
+        let modulus = modulus as u128;
+        self.count(|a, b| (a + b) % modulus) as u64
+    }
+
+    /// Returns an iterator over every shortest path from the start to any of the goals, inclusive.
+    ///
+    /// There can be exponentially many, so this is best used when [`AllPaths::count_paths`] is small.
+    pub fn paths(&self) -> impl Iterator<Item = Vec<S>> + '_ {
+        let search = &self.search;
+        let mut goals = search.goals.iter();
+        // Each frame is a node on the current route back from a goal, and how many of its parents have been tried.
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        std::iter::from_fn(move || loop {
+            if stack.is_empty() {
+                stack.push((*goals.next()?, 0));
+            }
+            let (i, tried) = *stack.last().unwrap();
+            if search.nodes[i].parent == NONE {
+                let path = stack
+                    .iter()
+                    .rev()
+                    .map(|&(i, _)| search.nodes[i].state.clone())
+                    .collect();
+                stack.pop();
+                return Some(path);
+            }
+            match search.predecessors(i).nth(tried) {
+                Some(parent) => {
+                    stack.last_mut().unwrap().1 += 1;
+                    stack.push((parent, 0));
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        })
+    }
+
+    /// Returns an iterator over every state that lies on at least one shortest path from the start to a goal.
+    pub fn states_on_paths(&self) -> impl Iterator<Item = &S> {
+        let search = &self.search;
+        let mut on_path = vec![false; search.nodes.len()];
+        let mut stack = search.goals.clone();
+        for &g in &stack {
+            on_path[g] = true;
+        }
+        while let Some(i) = stack.pop() {
+            for parent in search.predecessors(i) {
+                if !on_path[parent] {
+                    on_path[parent] = true;
+                    stack.push(parent);
+                }
+            }
+        }
+        search
+            .nodes
+            .iter()
+            .zip(on_path)
+            .filter(|(_, on_path)| *on_path)
+            .map(|(node, _)| &node.state)
+    }
+
+    /// Adds up the number of routes to each goal, visiting states in order of distance so that every predecessor of a
+    /// state is counted before it.
+    fn count(&self, add: impl Fn(u128, u128) -> u128) -> u128 {
+        let search = &self.search;
+        let mut order: Vec<usize> = (0..search.nodes.len())
+            .filter(|&i| search.nodes[i].settled)
+            .collect();
+        order.sort_by_key(|&i| search.nodes[i].distance);
+        let mut counts = vec![0; search.nodes.len()];
+        for i in order {
+            counts[i] = if search.nodes[i].parent == NONE {
+                1
+            } else {
+                search.predecessors(i).fold(0, |n, p| add(n, counts[p]))
+            };
+        }
+        search.goals.iter().fold(0, |n, &g| add(n, counts[g]))
+    }
+}
+
+/// Finds every shortest path from `start` to the states satisfying `goal`, where every step costs 1, using a
+/// breadth-first search.
+///
+/// Like [`bfs`], but the search continues until every goal at the shortest distance has been found,
+/// and all equally short routes to each state are kept.
+///
+/// # Examples
+/// ```
+/// use aoc::{search, Grid, Point};
+///
+/// let open = Grid::from_input(["...", "...", "..."]);
+/// let end = Point::new(2, 2);
+/// let found = search::bfs_all(Point::ZERO, |&p| open.neighbours4(p), |&p| p == end);
+///
+/// assert_eq!(found.distance(), Some(4));
+/// assert_eq!(found.count_paths(), 6);
+/// assert_eq!(found.count_paths_mod(4), 2);
+/// assert_eq!(found.paths().count(), 6);
+/// assert!(found.paths().all(|path| path.len() == 5 && path[0] == Point::ZERO && path[4] == end));
+/// assert_eq!(found.states_on_paths().count(), 9);
+/// assert_eq!(found.predecessors(&end).count(), 2);
+/// ```
+pub fn bfs_all<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> AllPaths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    AllPaths {
+        search: bfs_in(Search::new(start, true), successors, goal),
+    }
+}
+
+/// Finds every shortest path from `start` to the states satisfying `goal`, using Dijkstra's algorithm.
+///
+/// Like [`dijkstra`], but the search continues until every goal at the shortest distance has been found,
+/// and all equally short routes to each state are kept. Costs must be positive.
+///
+/// # Examples
+/// ```
+/// use aoc::{search, Dir4, Grid};
+///
+/// // Reindeer Maze: the best score, and the tiles on any best path.
+/// let maze = Grid::from_input([
+///     "###############",
+///     "#.......#....E#",
+///     "#.#.###.#.###.#",
+///     "#.....#.#...#.#",
+///     "#.###.#####.#.#",
+///     "#.#.#.......#.#",
+///     "#.#.#####.###.#",
+///     "#...........#.#",
+///     "###.#.#####.#.#",
+///     "#...#.....#.#.#",
+///     "#.#.#.###.#.#.#",
+///     "#.....#...#.#.#",
+///     "#.###.#.#.#.#.#",
+///     "#S..#.....#...#",
+///     "###############",
+/// ]);
+/// let start = (maze.position(&b'S').unwrap(), Dir4::Right);
+///
+/// let found = search::dijkstra_all(
+///     start,
+///     |&(p, dir)| {
+///         let forward = (maze[p + dir] != b'#').then_some(((p + dir, dir), 1));
+///         [((p, dir.turn_left()), 1000), ((p, dir.turn_right()), 1000)].into_iter().chain(forward)
+///     },
+///     |&(p, _)| maze[p] == b'E',
+/// );
+/// let mut tiles: Vec<_> = found.states_on_paths().map(|&(p, _)| p).collect();
+/// tiles.sort();
+/// tiles.dedup();
+///
+/// assert_eq!(found.distance(), Some(7036));
+/// assert_eq!(tiles.len(), 45);
+/// assert_eq!(found.count_paths(), 3);
+/// ```
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> AllPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    AllPaths {
+        search: astar_in(
+            Search::new(start, true),
+            successors,
+            |_| C::ZERO,
+            goal,
+            BinaryHeap::<Reverse<_>>::new(),
+        ),
+    }
+}
@@ -0,0 +1,55 @@
+use crate::Input;
+
+/// A trait for solutions that share state between parsing and the two parts.
+///
+/// Use this instead of free `part_1` and `part_2` functions when both parts need the same precomputed data,
+/// or when the solution should be configurable. Run it with `aoc::parts!(Type; 1, 2)`.
+///
+/// Both parts must be implemented, but only the parts listed in `aoc::parts!` are run.
+///
+/// Timings include the call to `parse`, so they are comparable with solutions written as free functions.
+///
+/// # Examples
+/// ```
+/// use aoc::{Input, Parse, Solution};
+///
+/// struct Day01 {
+///     depths: Vec<u32>,
+/// }
+///
+/// impl Solution for Day01 {
+///     type Answer1 = usize;
+///     type Answer2 = usize;
+///
+///     fn parse(input: Input) -> Self {
+///         Self {
+///             depths: input.lines().map(Parse::parse_uw).collect(),
+///         }
+///     }
+///
+///     fn part_1(&self, _input: Input) -> usize {
+///         self.depths.windows(2).filter(|w| w[1] > w[0]).count()
+///     }
+///
+///     fn part_2(&self, _input: Input) -> usize {
+///         self.depths.windows(4).filter(|w| w[3] > w[0]).count()
+///     }
+/// }
+///
+/// // aoc::parts!(Day01; 1, 2);
+/// ```
+pub trait Solution: Sized {
+    /// The type of the answer to part 1.
+    type Answer1: ToString;
+    /// The type of the answer to part 2.
+    type Answer2: ToString;
+
+    /// Builds the shared state from the puzzle input.
+    fn parse(input: Input) -> Self;
+
+    /// Solves part 1.
+    fn part_1(&self, input: Input) -> Self::Answer1;
+
+    /// Solves part 2.
+    fn part_2(&self, input: Input) -> Self::Answer2;
+}
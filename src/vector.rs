@@ -0,0 +1,346 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{automaton::Coord, Point};
+
+/// The primitive integer types, for generic geometry and range types.
+pub trait Integer:
+    Copy
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + std::hash::Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    /// Returns the absolute difference between `self` and `other`.
+    fn abs_diff(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                #[inline(always)]
+                fn abs_diff(self, other: Self) -> Self {
+                    if self > other {
+                        self - other
+                    } else {
+                        other - self
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// An `N`-dimensional vector of integers.
+///
+/// Useful for points in 3 or more dimensions, which are often parsed with `Parse::ints`.
+///
+/// # Examples
+/// ```
+/// use aoc::{Parse, Vec3};
+///
+/// let a = Vec3::from("pos=<1,-2,3>".ints::<3, i64>());
+/// let b = Vec3::new([4, 0, -1]);
+///
+/// assert_eq!(a + b, Vec3::new([5, -2, 2]));
+/// assert_eq!(a.dot(b), 1);
+/// assert_eq!(a.cross(b), Vec3::new([2, 13, 8]));
+/// assert_eq!(a.manhattan(b), 9);
+/// assert_eq!(a.chebyshev(b), 4);
+/// assert_eq!(a.dist_sq(b), 29);
+/// assert_eq!(a.component_min(b), Vec3::new([1, -2, -1]));
+/// assert_eq!(a.to_string(), "1,-2,3");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector<const N: usize, T = i64>(pub [T; N]);
+
+/// A 2-dimensional [`Vector`].
+pub type Vec2<T = i64> = Vector<2, T>;
+/// A 3-dimensional [`Vector`].
+pub type Vec3<T = i64> = Vector<3, T>;
+/// A 4-dimensional [`Vector`].
+pub type Vec4<T = i64> = Vector<4, T>;
+
+impl<const N: usize, T: Integer> Vector<N, T> {
+    /// The zero vector.
+    pub const ZERO: Self = Self([T::ZERO; N]);
+
+    /// Creates a new `Vector` from its components.
+    #[inline(always)]
+    pub const fn new(components: [T; N]) -> Self {
+        Self(components)
+    }
+
+    /// Creates a new `Vector` with every component equal to `value`.
+    #[inline]
+    pub fn splat(value: T) -> Self {
+        Self([value; N])
+    }
+
+    /// Returns the dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        self.0
+            .into_iter()
+            .zip(other.0)
+            .fold(T::ZERO, |sum, (a, b)| sum + a * b)
+    }
+
+    /// Returns the sum of the components.
+    #[inline]
+    pub fn sum(self) -> T {
+        self.0.into_iter().fold(T::ZERO, |sum, a| sum + a)
+    }
+
+    /// Returns the Manhattan (taxicab) distance between `self` and `other`.
+    #[inline]
+    pub fn manhattan(self, other: Self) -> T {
+        self.zip(other, T::abs_diff).sum()
+    }
+
+    /// Returns the Chebyshev (king move) distance between `self` and `other`.
+    #[inline]
+    pub fn chebyshev(self, other: Self) -> T {
+        self.zip(other, T::abs_diff)
+            .0
+            .into_iter()
+            .fold(T::ZERO, Ord::max)
+    }
+
+    /// Returns the square of the Euclidean distance between `self` and `other`.
+    #[inline]
+    pub fn dist_sq(self, other: Self) -> T {
+        let d = self.zip(other, T::abs_diff);
+        d.dot(d)
+    }
+
+    /// Returns the component-wise minimum of `self` and `other`.
+    #[inline]
+    pub fn component_min(self, other: Self) -> Self {
+        self.zip(other, Ord::min)
+    }
+
+    /// Returns the component-wise maximum of `self` and `other`.
+    #[inline]
+    pub fn component_max(self, other: Self) -> Self {
+        self.zip(other, Ord::max)
+    }
+
+    /// Returns a vector with `f` applied to each component.
+    #[inline]
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Vector<N, U> {
+        Vector(self.0.map(f))
+    }
+
+    /// Returns a vector with `f` applied to each pair of corresponding components of `self` and `other`.
+    #[inline]
+    pub fn zip(self, other: Self, mut f: impl FnMut(T, T) -> T) -> Self {
+        let mut out = self;
+        for (a, b) in out.0.iter_mut().zip(other.0) {
+            *a = f(*a, b);
+        }
+        out
+    }
+}
+
+impl<T: Integer> Vec3<T> {
+    /// Returns the cross product of `self` and `other`.
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        let [a1, a2, a3] = self.0;
+        let [b1, b2, b3] = other.0;
+        Self([a2 * b3 - a3 * b2, a3 * b1 - a1 * b3, a1 * b2 - a2 * b1])
+    }
+}
+
+macro_rules! impl_accessors {
+    ($n:literal: $($name:ident $name_mut:ident $i:literal),*) => {
+        impl<T: Copy> Vector<$n, T> {
+            $(
+                #[doc = concat!("Returns component ", $i, ".")]
+                #[inline(always)]
+                pub fn $name(self) -> T {
+                    self.0[$i]
+                }
+
+                #[doc = concat!("Returns a mutable reference to component ", $i, ".")]
+                #[inline(always)]
+                pub fn $name_mut(&mut self) -> &mut T {
+                    &mut self.0[$i]
+                }
+            )*
+        }
+    };
+}
+
+impl_accessors!(2: x x_mut 0, y y_mut 1);
+impl_accessors!(3: x x_mut 0, y y_mut 1, z z_mut 2);
+impl_accessors!(4: x x_mut 0, y y_mut 1, z z_mut 2, w w_mut 3);
+
+impl<const N: usize, T: Integer> Default for Vector<N, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize, T> From<[T; N]> for Vector<N, T> {
+    #[inline(always)]
+    fn from(components: [T; N]) -> Self {
+        Self(components)
+    }
+}
+
+impl<const N: usize, T> From<Vector<N, T>> for [T; N] {
+    #[inline(always)]
+    fn from(v: Vector<N, T>) -> Self {
+        v.0
+    }
+}
+
+impl From<Point> for Vec2 {
+    #[inline]
+    fn from(p: Point) -> Self {
+        Self([p.x, p.y])
+    }
+}
+
+impl From<Vec2> for Point {
+    #[inline]
+    fn from(v: Vec2) -> Self {
+        Self::new(v.0[0], v.0[1])
+    }
+}
+
+impl<const N: usize, T> Index<usize> for Vector<N, T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for Vector<N, T> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize, T: Integer> Add for Vector<N, T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip(rhs, Add::add)
+    }
+}
+
+impl<const N: usize, T: Integer> Sub for Vector<N, T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip(rhs, Sub::sub)
+    }
+}
+
+impl<const N: usize, T: Integer> Mul<T> for Vector<N, T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|a| a * rhs)
+    }
+}
+
+impl<const N: usize, T: Integer> Div<T> for Vector<N, T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|a| a / rhs)
+    }
+}
+
+impl<const N: usize, T: Integer + Neg<Output = T>> Neg for Vector<N, T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.map(Neg::neg)
+    }
+}
+
+impl<const N: usize, T: Integer> AddAssign for Vector<N, T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize, T: Integer> SubAssign for Vector<N, T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize, T: Integer> MulAssign<T> for Vector<N, T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize, T: fmt::Display> fmt::Display for Vector<N, T> {
+    /// Writes the components separated by commas.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, a) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            a.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> Coord for Vector<N, i64> {
+    #[inline]
+    fn offset(self, offset: Self) -> Self {
+        self + offset
+    }
+
+    fn moore() -> Vec<Self> {
+        <[i64; N]>::moore().into_iter().map(Self).collect()
+    }
+
+    fn von_neumann() -> Vec<Self> {
+        <[i64; N]>::von_neumann().into_iter().map(Self).collect()
+    }
+}